@@ -2,13 +2,14 @@ use clap::{Parser as ClapParser, Subcommand};
 use glang_attributes::StandardError;
 use glang_interpreter::interpret;
 use glang_lexer::lex;
-use glang_parser::parse;
+use glang_parser::{check, parse};
 use glang_tooling::log_error;
 use std::{
     env, fs,
     io::{Write, stdin, stdout},
     panic,
     path::Path,
+    process,
     time::Instant,
 };
 
@@ -36,6 +37,8 @@ enum Commands {
     },
     #[command(about = "Run a string of glang source code")]
     Run { code: String },
+    #[command(about = "Check the type annotations of a '.glang' file without running it")]
+    Check { file: String },
     #[command(about = "Install a '.kennel' file")]
     Install {
         name: String,
@@ -127,6 +130,18 @@ fn main() {
                 println!("{err}");
             }
         }
+        (Some(Commands::Check { file }), _) => {
+            if !file.ends_with(".glang") {
+                println!("Unable to read provided file (not a '.glang' file)");
+
+                process::exit(1);
+            }
+
+            // scripts can tell a program with type errors apart from one without
+            if !check_file(&file) {
+                process::exit(1);
+            }
+        }
         (Some(Commands::Install { name, force }), _) => {
             glang_tooling::add_package(&name, force);
         }
@@ -190,6 +205,40 @@ fn run(filename: &str, code: Option<String>) -> Option<StandardError> {
     error
 }
 
+/// Type check a '.glang' file and display every mismatch that was found
+///
+/// Returns whether the file could be read and had no type errors
+fn check_file(filename: &str) -> bool {
+    let contents = match fs::read_to_string(filename) {
+        Ok(contents) => contents,
+        Err(e) => {
+            println!("Unable to read '{filename}': {e}");
+
+            return false;
+        }
+    };
+
+    let errors = match lex(Path::new(filename), &contents) {
+        Ok(tokens) => match parse(&tokens, &contents) {
+            Ok(ast) => check(&ast),
+            Err(e) => vec![e],
+        },
+        Err(e) => vec![e],
+    };
+
+    for error in errors.iter() {
+        println!("{error}");
+    }
+
+    match errors.len() {
+        0 => println!("No type errors found in '{filename}'"),
+        1 => println!("Found 1 type error in '{filename}'"),
+        count => println!("Found {count} type errors in '{filename}'"),
+    }
+
+    errors.is_empty()
+}
+
 /// Starts the glang read evaluate print loop (REPL) using stdio
 fn launch_repl() {
     println!("George Language {VERSION}\nType '/exit' to exit");
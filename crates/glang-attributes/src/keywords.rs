@@ -3,26 +3,62 @@ pub const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_
 pub const LETTERS_DIGITS: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_";
 pub const BUILT_IN_FUNCTIONS: &[&str] = &[
     "bark", "chew", "dig", "bury", "copy", "clear", "tostring", "tonumber", "length", "uhoh",
-    "type", "_env", "_now", "split", "round",
+    "type", "_env", "_now", "split", "round", "iter", "range", "lines", "set",
 ];
+pub const STRING_METHODS: &[&str] = &[
+    "length",
+    "upper",
+    "lower",
+    "trim",
+    "split",
+    "contains",
+    "replace",
+    "starts_with",
+    "ends_with",
+];
+pub const LIST_METHODS: &[&str] = &[
+    "length", "push", "pop", "insert", "contains", "join", "reverse", "sort",
+];
+pub const MAP_METHODS: &[&str] = &["length", "keys", "values", "contains", "insert", "remove"];
+pub const SET_METHODS: &[&str] = &[
+    "length",
+    "contains",
+    "add",
+    "remove",
+    "union",
+    "intersection",
+    "difference",
+    "to_list",
+];
+pub const ITERATOR_METHODS: &[&str] = &["pull", "take", "collect"];
 pub const KEYWORDS: &[&str] = &[
     "obj",
     "stay",
+    "nonlocal",
     "and",
     "or",
     "not",
     "if",
     "also",
     "otherwise",
+    "sniff",
     "walk",
     "through",
     "step",
     "while",
     "try",
     "catch",
+    "finally",
     "func",
+    "breed",
     "fetch",
+    "as",
+    "from",
     "give",
+    "toss",
     "next",
     "leave",
+    "true",
+    "false",
+    "null",
 ];
@@ -5,7 +5,10 @@ mod position;
 mod span;
 mod standard_error;
 
-pub use keywords::{BUILT_IN_FUNCTIONS, DIGITS, KEYWORDS, LETTERS, LETTERS_DIGITS};
+pub use keywords::{
+    BUILT_IN_FUNCTIONS, DIGITS, ITERATOR_METHODS, KEYWORDS, LETTERS, LETTERS_DIGITS, LIST_METHODS,
+    MAP_METHODS, SET_METHODS, STRING_METHODS,
+};
 pub use position::Position;
 pub use span::Span;
-pub use standard_error::StandardError;
+pub use standard_error::{StandardError, TraceFrame};
@@ -1,7 +1,7 @@
 use crate::Position;
 use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Span {
     pub filename: PathBuf,
     pub start: Position,
@@ -2,23 +2,108 @@ use crate::Span;
 use simply_colored::*;
 use std::{fmt::Display, fs};
 
-#[derive(Clone)]
+// how many times in a row the same call is shown in a trace before the rest are counted instead
+const MAX_REPEATED_FRAMES: usize = 3;
+
+/// A function call that an error passed through on its way out
+#[derive(Debug, Clone)]
+pub struct TraceFrame {
+    pub function_name: String,
+    pub span: Span, // where the function was called
+}
+
+#[derive(Debug, Clone)]
 pub struct StandardError {
     pub text: String,
+    pub kind: String, // like 'TypeError', so that 'catch' can tell errors apart
     pub contents: Option<String>,
     pub span: Span,
     pub help: Option<String>,
-    pub error_propagates: bool,
+    pub trace: Vec<TraceFrame>, // the innermost call comes first
 }
 
 impl StandardError {
     pub fn new(text: &str, span: Span, help: Option<&str>) -> Self {
         Self {
             text: text.to_string(),
+            kind: "Error".to_string(),
             contents: None,
             span,
             help: help.map(|h| h.to_string()),
-            error_propagates: false,
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn with_kind(mut self, kind: &str) -> Self {
+        self.kind = kind.to_string();
+
+        self
+    }
+
+    /// Formats the calls the error passed through like a Python traceback, with the most recent
+    /// call last
+    ///
+    /// Each call is shown with the function it happened in, which is `<module>` for the calls
+    /// at the top level of a file. A call repeated many times in a row, like in deep recursion,
+    /// is only shown a few times
+    pub fn format_trace(&self) -> String {
+        let mut result = format!("{BOLD}|{RESET}\n{BOLD}| trace{RESET} (most recent call last):");
+        let mut previous: Option<(&TraceFrame, &str)> = None;
+        let mut repeats = 0;
+
+        for (i, frame) in self.trace.iter().enumerate().rev() {
+            let caller = match self.trace.get(i + 1) {
+                Some(caller) => caller.function_name.as_str(),
+                None => "<module>",
+            };
+
+            if previous.is_some_and(|(previous, previous_caller)| {
+                previous_caller == caller && previous.span == frame.span
+            }) {
+                repeats += 1;
+
+                if repeats >= MAX_REPEATED_FRAMES {
+                    continue;
+                }
+            } else {
+                result.push_str(&Self::format_repeats(repeats));
+                repeats = 0;
+            }
+
+            previous = Some((frame, caller));
+
+            let contents = match &self.contents {
+                Some(c) if frame.span.filename == self.span.filename => c.to_owned(),
+                _ => fs::read_to_string(&frame.span.filename).unwrap_or_default(),
+            };
+
+            result.push_str(&format!(
+                "\n{BOLD}|{RESET}   {}:{}:{}, in {caller}",
+                frame.span.filename.to_string_lossy(),
+                frame.span.start.line_num,
+                frame.span.start.column_num,
+            ));
+
+            if let Some(line) = contents
+                .lines()
+                .nth(frame.span.start.line_num.saturating_sub(1))
+            {
+                result.push_str(&format!("\n{BOLD}|{RESET}       {}", line.trim()));
+            }
+        }
+
+        result.push_str(&Self::format_repeats(repeats));
+
+        result
+    }
+
+    fn format_repeats(repeats: usize) -> String {
+        match repeats.checked_sub(MAX_REPEATED_FRAMES - 1) {
+            Some(hidden) if hidden > 0 => format!(
+                "\n{BOLD}|{RESET}   [the call above repeated {hidden} more time{}]",
+                if hidden > 1 { "s" } else { "" }
+            ),
+            _ => String::new(),
         }
     }
 
@@ -27,15 +112,30 @@ impl StandardError {
         let mut result = String::new();
 
         for i in span.start.line_num..=span.end.line_num {
-            if let Some(line) = lines.get((i - 1) as usize) {
+            if let Some(line) = i.checked_sub(1).and_then(|i| lines.get(i)) {
+                if !result.is_empty() {
+                    result.push('\n');
+                }
+
                 result.push_str(&format!("{BOLD}|{RESET} {}\n", line));
 
-                let col_start = span.start.column_num.saturating_sub(1);
-                let col_end = span.end.column_num.saturating_sub(1);
+                // spans across multiple lines highlight from the start of the span to the end of each line
+                let col_start = if i == span.start.line_num {
+                    span.start.column_num.saturating_sub(1)
+                } else {
+                    line.len() - line.trim_start().len()
+                };
+                let col_end = if i == span.end.line_num {
+                    span.end.column_num.saturating_sub(1)
+                } else {
+                    line.chars().count()
+                };
                 let arrow_len = (col_end.saturating_sub(col_start)).max(1);
                 let mut arrow_line = " ".repeat(col_start) + &"^".repeat(arrow_len);
 
-                if let Some(msg) = &self.help {
+                if let Some(msg) = &self.help
+                    && i == span.end.line_num
+                {
                     arrow_line.push_str(&format!(" {BOLD}{DIM_GREEN}help:{RESET} {}", msg));
                 }
 
@@ -61,7 +161,8 @@ impl Display for StandardError {
         };
 
         output.push_str(&format!(
-            "{BOLD}{DIM_RED}Error:{RESET} {}\n{BOLD}| in >{RESET} {}:{}:{}\n",
+            "{BOLD}{DIM_RED}{}:{RESET} {}\n{BOLD}| in >{RESET} {}:{}:{}\n",
+            self.kind,
             self.text,
             self.span.filename.to_string_lossy(),
             self.span.start.line_num,
@@ -73,6 +174,10 @@ impl Display for StandardError {
             self.format_code_as_messup(&contents, &self.span)
         ));
 
+        if !self.trace.is_empty() {
+            output.push_str(&format!("\n{}", self.format_trace()));
+        }
+
         write!(f, "{output}")
     }
 }
@@ -1,28 +1,115 @@
 use crate::{
-    BuiltInFunction, Context, Function, List, Number, RuntimeResult, Str, SymbolTable, Value,
+    ArgumentDefault, Boolean, Breed, BuiltInFunction, Context, Error, Function, Iter, List, Map,
+    Module, Null, Number, Parameters, RuntimeResult, Str, SymbolTable, TailCall, Value, Yielder,
 };
-use glang_attributes::{BUILT_IN_FUNCTIONS, Span, StandardError};
+use glang_attributes::{BUILT_IN_FUNCTIONS, Span, StandardError, TraceFrame};
 use glang_lexer::lex;
 use glang_parser::{
-    AstArena, AstNode, BinaryOperatorNode, CallNode, ConstAssignNode, ForEachNode, ForNode,
-    FunctionDefinitionNode, IfNode, ImportNode, ListNode, NodeID, NumberNode, ReturnNode,
-    StringNode, TryExceptNode, UnaryOperatorNode, VariableAccessNode, VariableAssignNode,
-    VariableRessignNode, WhileNode, parse,
+    AstArena, AstNode, BinaryOperatorNode, BooleanNode, BreedDefinitionNode, CallNode,
+    ConstAssignNode, DestructureNode, ForEachNode, ForNode, FunctionDefinitionNode, IfNode,
+    ImportNode, IndexAssignNode, IndexNode, InterpolationNode, ListNode, MapNode, MatchNode,
+    MemberAccessNode, MemberAssignNode, NodeID, NullNode, NumberNode, Pattern, ReturnNode,
+    SliceNode, StringNode, TryExceptNode, UnaryOperatorNode, VariableAccessNode,
+    VariableAssignNode, VariableRessignNode, WhileNode, YieldNode, parse,
 };
 use glang_tooling::get_latest_version;
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::{HashMap, HashSet},
-    fs,
+    env, fs,
     path::{Path, PathBuf},
     rc::Rc,
+    thread,
     time::Instant,
 };
 
+/// How many calls can be running at once when `GLANG_RECURSION_LIMIT` isn't set
+pub const DEFAULT_RECURSION_LIMIT: usize = 1000;
+
+/// The highest `GLANG_RECURSION_LIMIT` can go, larger values are lowered to it
+pub const MAX_RECURSION_LIMIT: usize = 100_000;
+
+// a typical call and the nodes it visits on the way to the next call fit in this much stack,
+// deeper nesting is still caught by the stack check in 'visit'
+const STACK_SIZE_PER_CALL: usize = 64 * 1024;
+const MAX_STACK_SIZE: usize = 1024 * 1024 * 1024;
+
+// how much stack is kept free below the point where 'visit' stops, for the functions it calls
+const STACK_RED_ZONE: usize = 256 * 1024;
+
+/// Returns how many calls can be running at once, which `GLANG_RECURSION_LIMIT` can change
+pub fn recursion_limit() -> Result<usize, StandardError> {
+    let Ok(limit) = env::var("GLANG_RECURSION_LIMIT") else {
+        return Ok(DEFAULT_RECURSION_LIMIT);
+    };
+
+    match limit.trim().parse::<usize>() {
+        Ok(limit) if limit > 0 => Ok(limit.min(MAX_RECURSION_LIMIT)),
+        _ => Err(StandardError::new(
+            format!("'GLANG_RECURSION_LIMIT' must be a whole number above 0, not '{limit}'")
+                .as_str(),
+            Span::empty(),
+            None,
+        )
+        .with_kind("ValueError")),
+    }
+}
+
+/// Returns how much stack the interpreter needs to reach the recursion limit without overflowing
+fn stack_size(recursion_limit: usize) -> usize {
+    recursion_limit
+        .saturating_add(16)
+        .saturating_mul(STACK_SIZE_PER_CALL)
+        .min(MAX_STACK_SIZE)
+}
+
+/// Returns roughly where the top of the stack is right now
+fn stack_position() -> usize {
+    let marker = 0u8;
+
+    std::hint::black_box(&marker) as *const u8 as usize
+}
+
 pub fn interpret(ast: AstArena, contents: &str) -> Option<StandardError> {
+    let contents = contents.to_owned();
+    let recursion_limit = match recursion_limit() {
+        Ok(limit) => limit,
+        Err(e) => return Some(e),
+    };
+    let stack_size = stack_size(recursion_limit);
+
+    // the main thread's stack is too small for deep recursion, so the program gets its own
+    let interpreter_thread = thread::Builder::new()
+        .name("interpreter".to_string())
+        .stack_size(stack_size)
+        .spawn(move || interpret_on_this_thread(ast, &contents, recursion_limit, stack_size));
+
+    match interpreter_thread {
+        Ok(handle) => handle
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic)),
+        Err(e) => Some(
+            StandardError::new(
+                format!("couldn't make a stack for the program to run on: {e}").as_str(),
+                Span::empty(),
+                Some("lower 'GLANG_RECURSION_LIMIT' so the program needs less stack"),
+            )
+            .with_kind("MemoryError"),
+        ),
+    }
+}
+
+fn interpret_on_this_thread(
+    ast: AstArena,
+    contents: &str,
+    recursion_limit: usize,
+    stack_size: usize,
+) -> Option<StandardError> {
     let interpreting_time = Instant::now();
 
     let mut interpreter = Interpreter::new(ast.clone(), contents);
+    interpreter.recursion_limit = recursion_limit;
+    interpreter.set_stack(stack_position(), stack_size);
     let context = Rc::new(RefCell::new(Context::new(
         None,
         None,
@@ -44,12 +131,14 @@ pub fn interpret(ast: AstArena, contents: &str) -> Option<StandardError> {
         );
     }
 
-    if result.should_propagate() {
-        // if the error is propagating, it is already displayed in the terminal
-        None
-    } else {
-        result.error
-    }
+    // errors from code that isn't in a file, like '<eval>', need the source to be displayed
+    result.error.map(|mut e| {
+        if e.contents.is_none() && !e.span.filename.exists() {
+            e.contents = Some(contents.to_owned());
+        }
+
+        e
+    })
 }
 
 pub struct Interpreter {
@@ -57,7 +146,13 @@ pub struct Interpreter {
     pub cached_library: Option<Rc<RefCell<SymbolTable>>>,
     pub arena: Rc<AstArena>,
     cached_modules: Rc<RefCell<HashMap<PathBuf, Rc<RefCell<SymbolTable>>>>>,
+    loading_modules: Rc<RefCell<Vec<(PathBuf, Span)>>>, // modules still running, with the fetch that started each one
     contents: String,
+    pub yielder: Option<*const Yielder>, // only set on the interpreter inside of a generator's coroutine
+    pub recursion_limit: usize,
+    call_depth: Rc<Cell<usize>>, // how many calls are running, shared with generators
+    stack_floor: usize, // 'visit' stops with an error once the stack grows below this address
+    pub tail_call_target: Option<Rc<Parameters>>, // the function whose body is running, if its calls to itself can be tail calls
 }
 
 impl Interpreter {
@@ -66,8 +161,14 @@ impl Interpreter {
             global_symbol_table: Rc::new(RefCell::new(SymbolTable::new(None))),
             cached_library: None,
             cached_modules: Rc::new(RefCell::new(HashMap::new())),
+            loading_modules: Rc::new(RefCell::new(Vec::new())),
             arena: Rc::new(arena),
             contents: contents.to_owned(),
+            yielder: None,
+            recursion_limit: recursion_limit().unwrap_or(DEFAULT_RECURSION_LIMIT),
+            call_depth: Rc::new(Cell::new(0)),
+            stack_floor: 0,
+            tail_call_target: None,
         };
 
         for builtin in BUILT_IN_FUNCTIONS {
@@ -93,6 +194,59 @@ impl Interpreter {
         interpreter
     }
 
+    /// Returns an interpreter that shares the globals, library and modules of this one
+    pub fn fork(&self) -> Self {
+        Self {
+            global_symbol_table: self.global_symbol_table.clone(),
+            cached_library: self.cached_library.clone(),
+            arena: self.arena.clone(),
+            cached_modules: self.cached_modules.clone(),
+            loading_modules: self.loading_modules.clone(),
+            contents: self.contents.clone(),
+            yielder: None,
+            recursion_limit: self.recursion_limit,
+            call_depth: self.call_depth.clone(),
+            stack_floor: self.stack_floor,
+            tail_call_target: None,
+        }
+    }
+
+    /// Tells the interpreter which stack it runs on, so deep nesting raises an error before
+    /// the stack overflows
+    ///
+    /// `top` is the highest address of the stack, which grows down by `size` bytes
+    pub fn set_stack(&mut self, top: usize, size: usize) {
+        self.stack_floor = top.saturating_sub(size).saturating_add(STACK_RED_ZONE);
+    }
+
+    /// Counts a call that is starting, or returns an error if there are already too many running
+    ///
+    /// Every call that starts successfully must be ended with `exit_call`
+    pub fn enter_call(&self, span: &Span) -> Result<(), StandardError> {
+        if self.call_depth.get() >= self.recursion_limit {
+            return Err(StandardError::new(
+                "too much recursion",
+                span.clone(),
+                Some(
+                    format!(
+                        "more than {} calls were running at once, set 'GLANG_RECURSION_LIMIT' to allow more",
+                        self.recursion_limit
+                    )
+                    .as_str(),
+                ),
+            )
+            .with_kind("RecursionError"));
+        }
+
+        self.call_depth.set(self.call_depth.get() + 1);
+
+        Ok(())
+    }
+
+    pub fn exit_call(&self) {
+        self.call_depth.set(self.call_depth.get().saturating_sub(1));
+    }
+
     pub fn preload_library(&mut self, context: Rc<RefCell<Context>>) {
         if let Some(e) = self.evaluate("fetch \"lib@latest\";", context.clone()) {
             println!("{}", e);
@@ -129,19 +283,41 @@ impl Interpreter {
         arena: &AstArena,
         context: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
+        // expressions nested inside of each other use stack without making calls, so the stack
+        // itself is checked too
+        if stack_position() < self.stack_floor {
+            return RuntimeResult::new().failure(
+                StandardError::new(
+                    "too much recursion",
+                    arena.span(node),
+                    Some("the program nested too many calls or expressions inside of each other"),
+                )
+                .with_kind("RecursionError"),
+            );
+        }
+
         let node = arena.get(node);
 
         match node {
             AstNode::List(node) => self.visit_list_node(node, arena, context),
+            AstNode::Map(node) => self.visit_map_node(node, arena, context),
+            AstNode::Null(node) => self.visit_null_node(node, context),
+            AstNode::Boolean(node) => self.visit_boolean_node(node, context),
             AstNode::Number(node) => self.visit_number_node(node, context),
+            AstNode::Index(node) => self.visit_index_node(node, arena, context),
+            AstNode::IndexAssign(node) => self.visit_index_assign_node(node, arena, context),
+            AstNode::Slice(node) => self.visit_slice_node(node, arena, context),
             AstNode::Strings(node) => self.visit_string_node(node, context),
+            AstNode::Interpolation(node) => self.visit_interpolation_node(node, arena, context),
             AstNode::VariableAssign(node) => self.visit_variable_assign_node(node, arena, context),
             AstNode::VariableReassign(node) => {
                 self.visit_variable_reassign_node(node, arena, context)
             }
             AstNode::ConstAssign(node) => self.visit_const_assign_node(node, arena, context),
+            AstNode::Destructure(node) => self.visit_destructure_node(node, arena, context),
             AstNode::VariableAccess(node) => self.visit_variable_access_node(node, context),
             AstNode::If(node) => self.visit_if_node(node, arena, context),
+            AstNode::Match(node) => self.visit_match_node(node, arena, context),
             AstNode::Import(node) => self.visit_import_node(node, arena, context),
             AstNode::For(node) => self.visit_for_node(node, arena, context),
             AstNode::ForEach(node) => self.visit_for_each_node(node, arena, context),
@@ -150,17 +326,51 @@ impl Interpreter {
             AstNode::FunctionDefinition(node) => {
                 self.visit_function_definition_node(node, arena, context)
             }
-            AstNode::Call(node) => self.visit_call_node(node, arena, context),
+            AstNode::Call(node) => self.visit_call_node(node, arena, context, false),
+            AstNode::BreedDefinition(node) => {
+                self.visit_breed_definition_node(node, arena, context)
+            }
+            AstNode::MemberAccess(node) => self.visit_member_access_node(node, arena, context),
+            AstNode::MemberAssign(node) => self.visit_member_assign_node(node, arena, context),
             AstNode::BinaryOperator(node) => self.visit_binary_operator_node(node, arena, context),
             AstNode::UnaryOperator(node) => self.visit_unary_operator_node(node, arena, context),
             AstNode::Return(node) => self.visit_return_node(node, arena, context),
+            AstNode::Yield(node) => self.visit_yield_node(node, arena, context),
             AstNode::Continue(_) => self.visit_continue_node(),
             AstNode::Break(_) => self.visit_break_node(),
         }
     }
 
+    fn visit_null_node(&self, node: &NullNode, context: Rc<RefCell<Context>>) -> RuntimeResult {
+        let value = Null::null_value();
+        value.borrow_mut().set_context(Some(context.clone()));
+        value.borrow_mut().set_span(node.span.clone());
+
+        RuntimeResult::new().success(value)
+    }
+
+    fn visit_boolean_node(
+        &self,
+        node: &BooleanNode,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let value = Boolean::from(node.value);
+        value.borrow_mut().set_context(Some(context.clone()));
+        value.borrow_mut().set_span(node.span.clone());
+
+        RuntimeResult::new().success(value)
+    }
+
     fn visit_number_node(&self, node: &NumberNode, context: Rc<RefCell<Context>>) -> RuntimeResult {
-        let value = Number::from(node.value);
+        let value = match Number::parse(&node.value) {
+            Some(number) => Number::from(number),
+            None => {
+                return RuntimeResult::new().failure(
+                    StandardError::new("invalid numerical value", node.span.clone(), None)
+                        .with_kind("ValueError"),
+                );
+            }
+        };
         value.borrow_mut().set_context(Some(context.clone()));
         value.borrow_mut().set_span(node.span.clone());
 
@@ -194,6 +404,199 @@ impl Interpreter {
         result.success(list)
     }
 
+    fn visit_map_node(
+        &mut self,
+        node: &MapNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut map = Map::new(Vec::new());
+
+        for (key_node, value_node) in node.pairs.iter() {
+            let key = result.register(self.visit(key_node.to_owned(), arena, context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            if let Err(e) = Map::check_key(&key) {
+                return result.failure(e);
+            }
+
+            let value = result.register(self.visit(value_node.to_owned(), arena, context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            map.insert(key, value);
+        }
+
+        let map = Rc::new(RefCell::new(Value::MapValue(map)));
+        map.borrow_mut().set_context(Some(context.clone()));
+        map.borrow_mut().set_span(node.span.clone());
+
+        result.success(map)
+    }
+
+    fn visit_interpolation_node(
+        &mut self,
+        node: &InterpolationNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut output = String::new();
+
+        for part_node in node.part_nodes.iter() {
+            let part = result.register(self.visit(*part_node, arena, context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            // every part is converted the same way 'tostring' converts values
+            output.push_str(&part.borrow().as_string());
+        }
+
+        let string = Str::from(&output);
+        string.borrow_mut().set_context(Some(context.clone()));
+        string.borrow_mut().set_span(node.span.clone());
+
+        result.success(string)
+    }
+
+    fn visit_index_node(
+        &mut self,
+        node: &IndexNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let object = result.register(self.visit(node.node_to_index, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let index = result.register(self.visit(node.index_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let value = object.borrow().index(index);
+
+        match value {
+            Ok(value) => result.success(value),
+            Err(e) => result.failure(e),
+        }
+    }
+
+    fn visit_index_assign_node(
+        &mut self,
+        node: &IndexAssignNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        // constants are copied when accessed, so the copy would be changed instead of the constant
+        if let AstNode::VariableAccess(target) = arena.get(node.node_to_index)
+            && self.is_constant(&target.name, context.clone())
+        {
+            return result.failure(
+                StandardError::new("cannot change a constant value", node.span.clone(), None)
+                    .with_kind("ConstantError"),
+            );
+        }
+
+        let object = result.register(self.visit(node.node_to_index, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let index = result.register(self.visit(node.index_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut value = result.register(self.visit(node.value_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        if let Some(operator) = &node.operator {
+            let current = object.borrow().index(index.clone());
+
+            match current
+                .and_then(|current| Self::compound_value(current, operator, value, &node.span))
+            {
+                Ok(new_value) => value = new_value,
+                Err(e) => return result.failure(e),
+            }
+        }
+
+        let assigned = object.borrow_mut().set_index(index, value.clone());
+
+        match assigned {
+            Ok(_) => result.success(value),
+            Err(e) => result.failure(e),
+        }
+    }
+
+    fn visit_slice_node(
+        &mut self,
+        node: &SliceNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let object = result.register(self.visit(node.node_to_slice, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut bounds = Vec::new();
+
+        for bound_node in [node.start_node, node.end_node, node.step_node] {
+            match bound_node {
+                Some(bound_node) => {
+                    bounds.push(Some(result.register(self.visit(
+                        bound_node,
+                        arena,
+                        context.clone(),
+                    ))));
+
+                    if result.should_return() {
+                        return result;
+                    }
+                }
+                None => bounds.push(None),
+            }
+        }
+
+        let step = bounds.pop().unwrap();
+        let end = bounds.pop().unwrap();
+        let start = bounds.pop().unwrap();
+        let value = object.borrow().slice(start, end, step);
+
+        match value {
+            Ok(value) => {
+                value.borrow_mut().set_context(Some(context.clone()));
+                value.borrow_mut().set_span(node.span.clone());
+
+                result.success(value)
+            }
+            Err(e) => result.failure(e),
+        }
+    }
+
     fn visit_string_node(
         &mut self,
         node: &StringNode,
@@ -216,11 +619,14 @@ impl Interpreter {
         let var_name = node.name.clone();
 
         if self.is_constant(&var_name, context.clone()) {
-            return result.failure(StandardError::new(
-                "cannot reassign the value of a constant",
-                node.span.clone(),
-                None,
-            ));
+            return result.failure(
+                StandardError::new(
+                    "cannot reassign the value of a constant",
+                    node.span.clone(),
+                    None,
+                )
+                .with_kind("ConstantError"),
+            );
         }
 
         let value = result.register(self.visit(node.value_node, &arena, context.clone()));
@@ -248,38 +654,74 @@ impl Interpreter {
         let var_name = node.name.clone();
 
         if self.is_constant(&var_name, context.clone()) {
-            return result.failure(StandardError::new(
-                "cannot reassign the value of a constant",
-                node.span.clone(),
-                None,
-            ));
+            return result.failure(
+                StandardError::new(
+                    "cannot reassign the value of a constant",
+                    node.span.clone(),
+                    None,
+                )
+                .with_kind("ConstantError"),
+            );
         }
 
-        if context
-            .borrow()
-            .symbol_table
-            .borrow()
-            .get(&var_name)
-            .is_none()
-        {
-            return result.failure(StandardError::new(
-                format!("variable name '{var_name}' is undefined").as_str(),
-                node.span.clone(),
-                Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
-            ));
-        }
+        let symbol_table = context.borrow().symbol_table.clone();
+        let is_local = symbol_table.borrow().symbols.contains_key(&var_name);
+        let enclosing_scope = symbol_table.borrow().enclosing_scope(&var_name);
 
-        let value = result.register(self.visit(node.value_node, &arena, context.clone()));
+        // assigning to a variable from an enclosing scope makes a local copy of it, and only
+        // 'nonlocal' changes the variable in the scope that defined it
+        let (target, current) = match (node.is_nonlocal, is_local, enclosing_scope) {
+            (false, true, _) => {
+                let current = symbol_table.borrow().symbols[&var_name].clone();
+
+                (symbol_table, current)
+            }
+            (true, _, Some(scope)) => {
+                let current = scope.borrow().symbols[&var_name].clone();
+
+                (scope, current)
+            }
+            (false, false, Some(scope)) => {
+                let current = scope.borrow().symbols[&var_name].clone();
+
+                (symbol_table, current)
+            }
+            (true, _, None) => {
+                return result.failure(
+                    StandardError::new(
+                        format!("no enclosing scope defines the variable '{var_name}'").as_str(),
+                        node.span.clone(),
+                        Some("'nonlocal' can only change variables defined outside of the current function"),
+                    )
+                    .with_kind("NameError"),
+                );
+            }
+            (false, false, None) => {
+                return result.failure(
+                    StandardError::new(
+                        format!("variable name '{var_name}' is undefined").as_str(),
+                        node.span.clone(),
+                        Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
+                    )
+                    .with_kind("NameError"),
+                );
+            }
+        };
+
+        let mut value = result.register(self.visit(node.value_node, arena, context.clone()));
 
         if result.should_return() {
             return result;
         }
 
-        context
-            .borrow_mut()
-            .symbol_table
-            .borrow_mut()
-            .set(var_name, value.clone());
+        if let Some(operator) = &node.operator {
+            match Self::compound_value(current, operator, value, &node.span) {
+                Ok(new_value) => value = new_value,
+                Err(e) => return result.failure(e),
+            }
+        }
+
+        target.borrow_mut().set(var_name, value.clone());
 
         result.success(value)
     }
@@ -294,11 +736,14 @@ impl Interpreter {
         let const_name = node.name.clone();
 
         if self.is_constant(&const_name, context.clone()) {
-            return result.failure(StandardError::new(
-                "cannot reassign the value of a constant",
-                node.span.clone(),
-                None,
-            ));
+            return result.failure(
+                StandardError::new(
+                    "cannot reassign the value of a constant",
+                    node.span.clone(),
+                    None,
+                )
+                .with_kind("ConstantError"),
+            );
         }
 
         let value = result.register(self.visit(node.value_node, &arena, context.clone()));
@@ -341,11 +786,14 @@ impl Interpreter {
             .clone();
 
         if value.is_none() {
-            return result.failure(StandardError::new(
-                format!("variable name '{var_name}' is undefined").as_str(),
-                node.span.clone(),
-                Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
-            ));
+            return result.failure(
+                StandardError::new(
+                    format!("variable name '{var_name}' is undefined").as_str(),
+                    node.span.clone(),
+                    Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
+                )
+                .with_kind("NameError"),
+            );
         }
 
         if let Some(mut v) = value.clone() {
@@ -363,11 +811,14 @@ impl Interpreter {
 
             return result.success(v);
         } else {
-            return result.failure(StandardError::new(
-                format!("variable name '{var_name}' is undefined").as_str(),
-                node.span.clone(),
-                Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
-            ));
+            return result.failure(
+                StandardError::new(
+                    format!("variable name '{var_name}' is undefined").as_str(),
+                    node.span.clone(),
+                    Some("define a variable with the syntax 'obj <variable name> = <value>;'"),
+                )
+                .with_kind("NameError"),
+            );
         }
     }
 
@@ -396,7 +847,7 @@ impl Interpreter {
                 }
 
                 return result.success(if *should_return_null {
-                    Number::null_value()
+                    Null::null_value()
                 } else {
                     expr_value
                 });
@@ -412,13 +863,326 @@ impl Interpreter {
             }
 
             return result.success(if should_return_null {
-                Number::null_value()
+                Null::null_value()
             } else {
                 else_value
             });
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
+    }
+
+    fn visit_match_node(
+        &mut self,
+        node: &MatchNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let value = result.register(self.visit(node.value_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        for arm in node.arms.iter() {
+            let mut bindings = Vec::new();
+
+            match self.match_pattern(&arm.pattern, &value, arena, context.clone(), &mut bindings) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(error) => return result.failure(error),
+            }
+
+            for (name, _) in bindings.iter() {
+                if self.is_constant(name, context.clone()) {
+                    return result.failure(
+                        StandardError::new(
+                            "cannot reassign the value of a constant",
+                            node.span.clone(),
+                            None,
+                        )
+                        .with_kind("ConstantError"),
+                    );
+                }
+            }
+
+            // the guard sees the bindings in a scope of its own, so an arm whose guard fails
+            // leaves the variables around the 'sniff' untouched
+            if let Some(guard_node) = arm.guard_node {
+                let mut guard_table = SymbolTable::new(Some(context.borrow().symbol_table.clone()));
+
+                for (name, bound_value) in bindings.iter() {
+                    guard_table.set(name.clone(), bound_value.clone());
+                }
+
+                let guard_context = Rc::new(RefCell::new(Context::new(
+                    context.borrow().parent.clone(),
+                    context.borrow().parent_entry_span.clone(),
+                    Rc::new(RefCell::new(guard_table)),
+                )));
+                let guard_value = result.register(self.visit(guard_node, arena, guard_context));
+
+                if result.should_return() {
+                    return result;
+                }
+
+                if !guard_value.borrow().is_true() {
+                    continue;
+                }
+            }
+
+            for (name, bound_value) in bindings {
+                context
+                    .borrow()
+                    .symbol_table
+                    .borrow_mut()
+                    .set(name, bound_value);
+            }
+
+            let body_value = result.register(self.visit(arm.body_node, arena, context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            return result.success(if arm.should_return_null {
+                Null::null_value()
+            } else {
+                body_value
+            });
+        }
+
+        result.failure(
+            StandardError::new(
+                "no pattern matched the value",
+                arena.span(node.value_node),
+                Some(
+                    format!(
+                        "the value is '{}', add a '_' arm to handle every other value",
+                        value.borrow().as_string()
+                    )
+                    .as_str(),
+                ),
+            )
+            .with_kind("MatchError"),
+        )
+    }
+
+    /// Returns whether the value has the shape of the pattern
+    ///
+    /// Names bound by the pattern are only collected in `bindings`, so an arm that doesn't
+    /// match never changes any variables
+    fn match_pattern(
+        &mut self,
+        pattern: &Pattern,
+        value: &Rc<RefCell<Value>>,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+        bindings: &mut Vec<(String, Rc<RefCell<Value>>)>,
+    ) -> Result<bool, StandardError> {
+        match pattern {
+            Pattern::Literal(literal_node) => {
+                let mut result = RuntimeResult::new();
+                let literal = result.register(self.visit(*literal_node, arena, context));
+
+                if let Some(error) = result.error {
+                    return Err(error);
+                }
+
+                if literal.borrow().object_type() != value.borrow().object_type() {
+                    return Ok(false);
+                }
+
+                // the fresh literal is the left side, so constant values can be compared too
+                let is_equal = literal
+                    .borrow_mut()
+                    .perform_operation("==", value.clone())?;
+
+                Ok(is_equal.borrow().is_true())
+            }
+            Pattern::Binding(name_token) => {
+                bindings.push((name_token.value.clone(), value.clone()));
+
+                Ok(true)
+            }
+            Pattern::Type(type_token, name_token) => {
+                if value.borrow().object_type() != type_token.value {
+                    return Ok(false);
+                }
+
+                bindings.push((name_token.value.clone(), value.clone()));
+
+                Ok(true)
+            }
+            Pattern::List(element_patterns, rest_name_token) => {
+                let elements = match *value.borrow() {
+                    Value::ListValue(ref list) => list.elements.clone(),
+                    _ => return Ok(false),
+                };
+
+                let has_matching_length = match rest_name_token {
+                    Some(_) => elements.len() >= element_patterns.len(),
+                    None => elements.len() == element_patterns.len(),
+                };
+
+                if !has_matching_length {
+                    return Ok(false);
+                }
+
+                for (element_pattern, element) in element_patterns.iter().zip(elements.iter()) {
+                    if !self.match_pattern(
+                        element_pattern,
+                        element,
+                        arena,
+                        context.clone(),
+                        bindings,
+                    )? {
+                        return Ok(false);
+                    }
+                }
+
+                if let Some(rest_name_token) = rest_name_token {
+                    bindings.push((
+                        rest_name_token.value.clone(),
+                        List::from(elements[element_patterns.len()..].to_vec()),
+                    ));
+                }
+
+                Ok(true)
+            }
+        }
+    }
+
+    /// Binds the names of a pattern like '[head, ...rest]' to the parts of a value, and errors
+    /// when the value doesn't have the shape of the pattern
+    fn destructure(
+        &mut self,
+        pattern: &Pattern,
+        value: &Rc<RefCell<Value>>,
+        span: &Span,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+        bindings: &mut Vec<(String, Rc<RefCell<Value>>)>,
+    ) -> Result<(), StandardError> {
+        let Pattern::List(element_patterns, rest_name_token) = pattern else {
+            if !self.match_pattern(pattern, value, arena, context, bindings)? {
+                return Err(StandardError::new(
+                    "value doesn't match the pattern",
+                    span.clone(),
+                    Some(format!("the value is a {}", value.borrow().object_type()).as_str()),
+                )
+                .with_kind("MatchError"));
+            }
+
+            return Ok(());
+        };
+
+        let elements = match *value.borrow() {
+            Value::ListValue(ref list) => list.elements.clone(),
+            ref other => {
+                return Err(StandardError::new(
+                    "object can't be unpacked",
+                    span.clone(),
+                    Some(
+                        format!(
+                            "only lists can be unpacked, the object is a {}",
+                            other.object_type()
+                        )
+                        .as_str(),
+                    ),
+                )
+                .with_kind("TypeError"));
+            }
+        };
+
+        let has_matching_length = match rest_name_token {
+            Some(_) => elements.len() >= element_patterns.len(),
+            None => elements.len() == element_patterns.len(),
+        };
+
+        if !has_matching_length {
+            return Err(StandardError::new(
+                format!(
+                    "expected {}{} elements to unpack, but the list has {}",
+                    if rest_name_token.is_some() { "at least " } else { "" },
+                    element_patterns.len(),
+                    elements.len()
+                )
+                .as_str(),
+                span.clone(),
+                Some("make the number of names match the length of the list, or collect the rest with '...rest'"),
+            )
+            .with_kind("ValueError"));
+        }
+
+        for (element_pattern, element) in element_patterns.iter().zip(elements.iter()) {
+            self.destructure(
+                element_pattern,
+                element,
+                span,
+                arena,
+                context.clone(),
+                bindings,
+            )?;
+        }
+
+        if let Some(rest_name_token) = rest_name_token {
+            bindings.push((
+                rest_name_token.value.clone(),
+                List::from(elements[element_patterns.len()..].to_vec()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn visit_destructure_node(
+        &mut self,
+        node: &DestructureNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let value = result.register(self.visit(node.value_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut bindings = Vec::new();
+
+        if let Err(error) = self.destructure(
+            &node.pattern,
+            &value,
+            &node.span,
+            arena,
+            context.clone(),
+            &mut bindings,
+        ) {
+            return result.failure(error);
+        }
+
+        for (name, bound_value) in bindings {
+            if self.is_constant(&name, context.clone()) {
+                return result.failure(
+                    StandardError::new(
+                        "cannot reassign the value of a constant",
+                        node.span.clone(),
+                        None,
+                    )
+                    .with_kind("ConstantError"),
+                );
+            }
+
+            context
+                .borrow()
+                .symbol_table
+                .borrow_mut()
+                .set(name, bound_value);
+        }
+
+        result.success(value)
     }
 
     fn visit_for_node(
@@ -433,13 +1197,16 @@ impl Interpreter {
             .register(self.visit(node.start_value_node, &arena, context.clone()))
             .borrow()
         {
-            Value::NumberValue(ref value) => Number::new(value.value),
+            Value::NumberValue(ref value) => Number::new(value.value.clone()),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type number",
-                    arena.span(node.start_value_node),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type number",
+                        arena.span(node.start_value_node),
+                        None,
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
@@ -451,13 +1218,16 @@ impl Interpreter {
             .register(self.visit(node.end_value_node, &arena, context.clone()))
             .borrow()
         {
-            Value::NumberValue(ref value) => Number::new(value.value),
+            Value::NumberValue(ref value) => Number::new(value.value.clone()),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type number",
-                    arena.span(node.end_value_node),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type number",
+                        arena.span(node.end_value_node),
+                        None,
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
@@ -472,13 +1242,12 @@ impl Interpreter {
                 .register(self.visit(step_value_node.to_owned(), &arena, context.clone()))
                 .borrow()
             {
-                Value::NumberValue(ref value) => Number::new(value.value),
+                Value::NumberValue(ref value) => Number::new(value.value.clone()),
                 _ => {
-                    return result.failure(StandardError::new(
-                        "expected type number",
-                        start_value.span,
-                        None,
-                    ));
+                    return result.failure(
+                        StandardError::new("expected type number", start_value.span, None)
+                            .with_kind("TypeError"),
+                    );
                 }
             };
 
@@ -486,37 +1255,46 @@ impl Interpreter {
                 return result;
             }
         } else {
-            step_value = Number::new(1.0);
+            step_value = Number::new(1);
         }
 
-        if step_value.value == 0.0 {
+        if step_value.value.is_zero() {
             return result.failure(StandardError::new(
                 "step value of a 'walk' loop cannot be 0",
                 arena.span(node.step_value_node.unwrap()),
                 Some("use a step value like 'step = 1' to control how many iteration steps occur"),
-            ));
+            ).with_kind("ValueError"));
         }
 
         let iterator_name = node.iterator_name.clone();
         let symbol_table = context.borrow().symbol_table.clone();
 
-        let range: Vec<f64> = if step_value.value > 0.0 {
-            (start_value.value as i64..end_value.value as i64)
-                .step_by(step_value.value as usize)
-                .map(|x| x as f64)
-                .collect()
-        } else {
-            (end_value.value as i64 + 1..=start_value.value as i64)
-                .rev()
-                .step_by((-step_value.value) as usize)
-                .map(|x| x as f64)
-                .collect()
-        };
+        let step = Number::from(step_value.value.clone());
+        let mut i = Number::from(start_value.value.clone());
+
+        loop {
+            let is_in_range = match *i.borrow() {
+                Value::NumberValue(ref i) if step_value.value.is_negative() => {
+                    i.value > end_value.value
+                }
+                Value::NumberValue(ref i) => i.value < end_value.value,
+                _ => false,
+            };
+
+            if !is_in_range {
+                break;
+            }
 
-        for i in range {
             symbol_table
                 .borrow_mut()
-                .set(iterator_name.clone(), Number::from(i));
+                .set(iterator_name.clone(), i.clone());
+
+            // the counter is stepped here so that changing the iterator in the body has no effect
+            let next = i.borrow_mut().perform_operation("+", step.clone());
+            i = match next {
+                Ok(next) => next,
+                Err(error) => return result.failure(error),
+            };
 
             let _ = result.register(self.visit(node.body_node, &arena, context.clone()));
 
@@ -533,7 +1311,7 @@ impl Interpreter {
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn visit_for_each_node(
@@ -542,40 +1320,49 @@ impl Interpreter {
         arena: &AstArena,
         context: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
-        let mut result = RuntimeResult::new();
-
-        let elements = match *result
-            .register(self.visit(node.iterator_node, &arena, context.clone()))
-            .borrow()
-        {
-            Value::ListValue(ref v) => v.elements.clone(),
-            Value::StringValue(ref v) => {
-                let mut elements = Vec::new();
-
-                for char in v.value.chars() {
-                    elements.push(Str::from(&char.to_string()));
-                }
-
-                elements
-            }
-            _ => {
-                return result.failure(StandardError::new(
-                    "object is not iterable",
-                    arena.span(node.iterator_node),
-                    None,
-                ));
-            }
-        };
+        let mut result = RuntimeResult::new();
+
+        let value = result.register(self.visit(node.iterator_node, &arena, context.clone()));
 
         if result.should_return() {
             return result;
         }
 
-        let iterator_name = node.iterator_name.clone();
+        let iterator = match Iter::from_value(&value) {
+            Ok(iterator) => iterator,
+            Err(error) => return result.failure(error),
+        };
+        let iterator = match *iterator.borrow() {
+            Value::IteratorValue(ref iterator) => iterator.clone(),
+            _ => unreachable!(),
+        };
+
         let symbol_table = context.borrow().symbol_table.clone();
 
-        for i in elements {
-            symbol_table.borrow_mut().set(iterator_name.clone(), i);
+        // values are pulled one at a time, so generators and ranges never build a list
+        loop {
+            let i = match iterator.next() {
+                Ok(Some(i)) => i,
+                Ok(None) => break,
+                Err(error) => return result.failure(error),
+            };
+
+            let mut bindings = Vec::new();
+
+            if let Err(error) = self.destructure(
+                &node.iterator_pattern,
+                &i,
+                &node.span,
+                arena,
+                context.clone(),
+                &mut bindings,
+            ) {
+                return result.failure(error);
+            }
+
+            for (name, bound_value) in bindings {
+                symbol_table.borrow_mut().set(name, bound_value);
+            }
 
             let _ = result.register(self.visit(node.body_node, &arena, context.clone()));
 
@@ -592,7 +1379,7 @@ impl Interpreter {
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn visit_while_node(
@@ -630,7 +1417,7 @@ impl Interpreter {
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn visit_try_except_node(
@@ -638,36 +1425,66 @@ impl Interpreter {
         node: &TryExceptNode,
         arena: &AstArena,
         context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        // a call inside of 'try' isn't the last thing the function does, because its errors
+        // still have to be caught and 'finally' still has to run once it's done
+        let tail_call_target = self.tail_call_target.take();
+        let result = self.visit_try_except_clauses(node, arena, context);
+        self.tail_call_target = tail_call_target;
+
+        result
+    }
+
+    fn visit_try_except_clauses(
+        &mut self,
+        node: &TryExceptNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
 
         let _ = result.register(self.visit(node.try_body_node, &arena, context.clone()));
-        let try_error = result.error.clone();
 
-        if try_error.is_some() {
-            let output_error = Str::from(&try_error.unwrap().text);
-            output_error.borrow_mut().set_const(true);
+        if let Some(try_error) = result.error.clone() {
+            let clause = node.catch_clauses.iter().find(|clause| {
+                clause
+                    .kind
+                    .as_ref()
+                    .is_none_or(|kind| *kind == try_error.kind)
+            });
 
-            context
-                .borrow_mut()
-                .symbol_table
-                .borrow_mut()
-                .set(node.passed_error.clone(), output_error);
+            if let Some(clause) = clause {
+                let output_error = Error::from(try_error);
+                output_error.borrow_mut().set_const(true);
+                output_error.borrow_mut().set_context(Some(context.clone()));
 
-            let _ = result.register(self.visit(node.except_body_node, &arena, context));
+                context
+                    .borrow_mut()
+                    .symbol_table
+                    .borrow_mut()
+                    .set(clause.passed_error.clone(), output_error);
 
-            if result.error.is_some() {
-                return result;
+                let _ = result.register(self.visit(clause.body_node, arena, context.clone()));
             }
+        }
+
+        // 'finally' always runs, and only replaces what happened before if it stops early itself
+        if let Some(finally_body_node) = node.finally_body_node {
+            let outcome = result.clone();
+            let _ = result.register(self.visit(finally_body_node, arena, context));
 
             if result.should_return() {
                 return result;
             }
-        } else if result.should_return() {
+
+            result = outcome;
+        }
+
+        if result.should_return() {
             return result;
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn visit_import_node(
@@ -692,44 +1509,107 @@ impl Interpreter {
                 Err(e) => return result.failure(e),
             };
 
-        if file_to_import == importing_path {
-            return result.failure(StandardError::new(
-                "circular import (cannot import files into themselves)",
-                import_value.borrow().span(),
-                None,
-            ));
+        if let Some(chain) = self.import_cycle(&file_to_import, &importing_path) {
+            return result.failure(
+                StandardError::new(
+                    format!("circular import: {chain}").as_str(),
+                    import_value.borrow().span(),
+                    Some("a file can't be fetched while it is still loading, so move what they share into another file"),
+                )
+                .with_kind("ImportError"),
+            );
         }
 
+        let module_name = file_to_import
+            .file_stem()
+            .map_or(String::new(), |stem| stem.to_string_lossy().to_string());
+
         // if we already have imported modules stored, then use cached ones
-        if let Some(cached_symtab) = self.cached_modules.borrow().get(&file_to_import) {
-            for (name, value) in cached_symtab.borrow().symbols.clone() {
-                context
-                    .borrow_mut()
-                    .symbol_table
-                    .borrow_mut()
-                    .set(name, value);
+        let cached_symtab = self.cached_modules.borrow().get(&file_to_import).cloned();
+        let module_symtab = match cached_symtab {
+            Some(symtab) => symtab,
+            None => match self.run_module(&file_to_import, &import_value.borrow().span()) {
+                Ok(symtab) => symtab,
+                Err(mut e) => {
+                    // the fetch shows up in the trace, so an error can be followed into the module
+                    e.trace.push(TraceFrame {
+                        function_name: "<module>".to_string(),
+                        span: node.span.clone(),
+                    });
+
+                    return result.failure(e);
+                }
+            },
+        };
+
+        let module = Module::from(module_name.clone(), module_symtab.clone());
+        module.borrow_mut().set_context(Some(context.clone()));
+        module.borrow_mut().set_span(node.span.clone());
+
+        let imported = if let Some(alias) = &node.alias {
+            vec![(alias.value.clone(), module.clone())]
+        } else if !node.names.is_empty() {
+            let mut imported = Vec::new();
+
+            for name in node.names.iter() {
+                match module_symtab.borrow().symbols.get(&name.value) {
+                    Some(value) => imported.push((name.value.clone(), value.clone())),
+                    None => {
+                        return result.failure(
+                            StandardError::new(
+                                format!("module '{module_name}' has no member '{}'", name.value)
+                                    .as_str(),
+                                name.span.clone(),
+                                name.value.starts_with('_').then_some(
+                                    "names starting with '_' are private to their module",
+                                ),
+                            )
+                            .with_kind("ImportError"),
+                        );
+                    }
+                }
             }
 
-            return result.success(Number::null_value());
+            imported
+        } else {
+            module_symtab.borrow().symbols.clone().into_iter().collect()
+        };
+
+        for (name, value) in imported {
+            context
+                .borrow_mut()
+                .symbol_table
+                .borrow_mut()
+                .set(name, value);
         }
 
+        result.success(module)
+    }
+
+    /// Runs the file as a module and returns its top level, which is cached for later imports
+    fn run_module(
+        &mut self,
+        file_to_import: &PathBuf,
+        span: &Span,
+    ) -> Result<Rc<RefCell<SymbolTable>>, StandardError> {
         let mut contents = String::new();
 
-        match fs::read_to_string(&file_to_import) {
+        match fs::read_to_string(file_to_import) {
             Ok(extra) => contents.push_str(&extra),
             Err(_) => {
-                return result.failure(StandardError::new(
+                return Err(StandardError::new(
                     &format!(
                         "file contents couldn't be read properly on {}",
                         file_to_import.to_string_lossy()
                     ),
-                    import_value.borrow().span(),
+                    span.clone(),
                     Some("add a UTF-8 encoded '.glang' file to import"),
-                ));
+                )
+                .with_kind("FileError"));
             }
         }
 
-        let ast_result = match lex(&file_to_import, &contents) {
+        let ast_result = match lex(file_to_import, &contents) {
             Ok(tokens) => match parse(&tokens, &contents) {
                 Ok(ast) => Ok(ast),
                 Err(e) => Err(e),
@@ -739,12 +1619,16 @@ impl Interpreter {
 
         let ast_node = match ast_result {
             Ok(ast_node) => ast_node,
-            Err(e) => return result.failure(e),
+            Err(e) => return Err(e.with_kind("SyntaxError")),
         };
 
         let mut interpreter = Interpreter::new(ast_node.clone(), &contents);
         interpreter.cached_library = self.cached_library.clone();
         interpreter.cached_modules = self.cached_modules.clone();
+        interpreter.loading_modules = self.loading_modules.clone();
+        interpreter.recursion_limit = self.recursion_limit;
+        interpreter.call_depth = self.call_depth.clone();
+        interpreter.stack_floor = self.stack_floor;
         let module_context = Rc::new(RefCell::new(Context::new(
             None,
             None,
@@ -761,36 +1645,47 @@ impl Interpreter {
             }
         }
 
+        let preloaded = module_context
+            .borrow()
+            .symbol_table
+            .borrow()
+            .symbols
+            .clone();
+
+        self.loading_modules
+            .borrow_mut()
+            .push((file_to_import.clone(), span.clone()));
         let module_result = interpreter.visit(
             NodeID(ast_node.nodes.len() - 1),
             &interpreter.arena.clone(),
             module_context.clone(),
         );
+        self.loading_modules.borrow_mut().pop();
 
         if let Some(e) = module_result.error {
-            return result.failure(e);
+            return Err(e);
         }
 
-        self.cached_modules.borrow_mut().insert(
-            file_to_import.clone(),
-            module_context.borrow().symbol_table.clone(),
-        );
+        // only the names the module defined itself are exported, and names starting with '_'
+        // stay private, though the module's own functions can still use them
+        let mut exports = SymbolTable::new(None);
 
-        for (name, value) in module_context
-            .borrow()
-            .symbol_table
-            .borrow()
-            .symbols
-            .clone()
-        {
-            context
-                .borrow_mut()
-                .symbol_table
-                .borrow_mut()
-                .set(name, value);
+        for (name, value) in module_context.borrow().symbol_table.borrow().symbols.iter() {
+            let is_preloaded = preloaded
+                .get(name)
+                .is_some_and(|preloaded_value| Rc::ptr_eq(preloaded_value, value));
+
+            if !name.starts_with('_') && !is_preloaded {
+                exports.set(name.clone(), value.clone());
+            }
         }
 
-        result.success(Number::null_value())
+        let module_symtab = Rc::new(RefCell::new(exports));
+        self.cached_modules
+            .borrow_mut()
+            .insert(file_to_import.clone(), module_symtab.clone());
+
+        Ok(module_symtab)
     }
 
     fn visit_function_definition_node(
@@ -807,31 +1702,49 @@ impl Interpreter {
             "".to_string()
         };
         let body_node = node.body_node.clone();
-        let mut arg_names: Vec<String> = Vec::new();
-
-        for arg_name_tok in node.argument_names.iter() {
-            arg_names.push(arg_name_tok.value.clone());
-        }
-
         let mut seen = HashSet::new();
 
-        for (i, name) in arg_names.iter().enumerate() {
-            if !seen.insert(name) {
-                return result.failure(StandardError::new(
-                    "duplicate argument",
-                    node.argument_names[i].span.clone(),
-                    Some(format!("remove the duplicate argument '{}'", name).as_str()),
-                ));
+        for arg_name_tok in node.argument_names.iter().chain(&node.rest_argument_name) {
+            if !seen.insert(&arg_name_tok.value) {
+                return result.failure(
+                    StandardError::new(
+                        "duplicate argument",
+                        arg_name_tok.span.clone(),
+                        Some(
+                            format!("remove the duplicate argument '{}'", arg_name_tok.value)
+                                .as_str(),
+                        ),
+                    )
+                    .with_kind("ArgumentError"),
+                );
             }
         }
 
-        let func_value = Rc::new(RefCell::new(Value::FunctionValue(Function::new(
-            func_name.clone(),
-            body_node,
-            arena.to_owned(),
-            &arg_names,
-            node.should_auto_return,
-        ))));
+        let parameters = Parameters::new(
+            node.argument_names
+                .iter()
+                .map(|arg_name_tok| arg_name_tok.value.clone())
+                .collect(),
+            node.default_nodes
+                .iter()
+                .map(|default_node| default_node.map(ArgumentDefault::Node))
+                .collect(),
+            node.rest_argument_name
+                .as_ref()
+                .map(|rest_name_tok| rest_name_tok.value.clone()),
+        );
+
+        let func_value = Rc::new(RefCell::new(Value::FunctionValue(
+            Function::new(
+                func_name.clone(),
+                body_node,
+                arena.to_owned(),
+                parameters,
+                node.should_auto_return,
+                node.is_generator,
+            )
+            .with_environment(context.borrow().symbol_table.clone()),
+        )));
         func_value.borrow_mut().set_context(Some(context.clone()));
         func_value.borrow_mut().set_span(node.span.clone());
 
@@ -846,14 +1759,18 @@ impl Interpreter {
         result.success(func_value)
     }
 
+    /// Calls a value, or hands the arguments back as a tail call if `is_tail` is set and the
+    /// function whose body is running is calling itself
     fn visit_call_node(
         &mut self,
         node: &CallNode,
         arena: &AstArena,
         context: Rc<RefCell<Context>>,
+        is_tail: bool,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
         let mut args: Vec<Rc<RefCell<Value>>> = Vec::new();
+        let mut keyword_args: Vec<(String, Rc<RefCell<Value>>)> = Vec::new();
 
         let value_to_call = result.register(self.visit(node.node_to_call, &arena, context.clone()));
 
@@ -876,31 +1793,77 @@ impl Interpreter {
             args.push(arg);
         }
 
+        for (arg_name_token, arg_node) in &node.keyword_arg_nodes {
+            let arg = result.register(self.visit(*arg_node, arena, context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            keyword_args.push((arg_name_token.value.clone(), arg));
+        }
+
+        if is_tail
+            && let Value::FunctionValue(ref function) = *value_to_call.borrow()
+            && !function.is_generator
+            && self
+                .tail_call_target
+                .as_ref()
+                .is_some_and(|target| Rc::ptr_eq(target, &function.parameters))
+        {
+            return result.success_tail_call(TailCall {
+                bound_self: function.bound_self.clone(),
+                args,
+                keyword_args,
+            });
+        }
+
+        // runaway recursion stops with an error the program can catch, before the stack overflows
+        let is_counted = matches!(
+            *value_to_call.borrow(),
+            Value::FunctionValue(_) | Value::BreedValue(_)
+        );
+
+        if is_counted && let Err(error) = self.enter_call(&node.span) {
+            return result.failure(error);
+        }
+
         let return_value = result.register(match *value_to_call.borrow() {
-            Value::FunctionValue(ref value) => value.execute(&args, self),
-            Value::BuiltInFunction(ref value) => value.execute(&args),
+            Value::FunctionValue(ref value) => value.execute(&args, &keyword_args, self),
+            Value::BuiltInFunction(ref value) => value.execute(&args, &keyword_args),
+            Value::BreedValue(ref value) => value.execute(&args, &keyword_args, self),
             _ => {
-                return result.failure(StandardError::new(
-                    "object is not callable",
-                    node.span.clone(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new("object is not callable", node.span.clone(), None)
+                        .with_kind("TypeError"),
+                );
             }
         });
 
-        if result.should_return() {
-            // if the call contains an error from 'uhoh', propagate it upward
-            if result.should_propagate() {
-                let err = result.error.as_mut().unwrap();
-                err.span = node.span.clone();
+        if is_counted {
+            self.exit_call();
+        }
 
-                if !err.span.filename.exists() {
-                    err.contents = Some(self.contents.clone());
+        // built-in functions don't get a frame, their errors already point at the call
+        if let Some(err) = result.error.as_mut() {
+            let function_name = match *value_to_call.borrow() {
+                Value::FunctionValue(ref value) if value.name.is_empty() => {
+                    Some("<anonymous>".to_string())
                 }
+                Value::FunctionValue(ref value) => Some(value.name.clone()),
+                Value::BreedValue(ref value) => Some(value.name.clone()),
+                _ => None,
+            };
 
-                println!("{err}");
+            if let Some(function_name) = function_name {
+                err.trace.push(TraceFrame {
+                    function_name,
+                    span: node.span.clone(),
+                });
             }
+        }
 
+        if result.should_return() {
             return result;
         }
 
@@ -910,6 +1873,154 @@ impl Interpreter {
         result.success(return_value)
     }
 
+    fn visit_breed_definition_node(
+        &mut self,
+        node: &BreedDefinitionNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let mut methods = HashMap::new();
+
+        // methods are defined in their own scope so they don't leak into the surrounding one
+        let breed_context = Rc::new(RefCell::new(Context::new(
+            Some(context.clone()),
+            Some(node.span.clone()),
+            Rc::new(RefCell::new(SymbolTable::new(Some(
+                context.borrow().symbol_table.clone(),
+            )))),
+        )));
+
+        for method_node in node.method_nodes.iter() {
+            let method = result.register(self.visit(*method_node, arena, breed_context.clone()));
+
+            if result.should_return() {
+                return result;
+            }
+
+            if let Value::FunctionValue(ref method) = *method.borrow() {
+                if method.parameters.names.is_empty() {
+                    return result.failure(StandardError::new(
+                        "expected 'self' argument",
+                        arena.span(*method_node),
+                        Some(
+                            format!(
+                                "methods take the instance as their first argument, like 'func {}(self)'",
+                                method.name
+                            )
+                            .as_str(),
+                        ),
+                    ).with_kind("ArgumentError"));
+                }
+
+                if methods.contains_key(&method.name) {
+                    return result.failure(StandardError::new(
+                        "duplicate method",
+                        arena.span(*method_node),
+                        Some(format!("remove the duplicate method '{}'", method.name).as_str()),
+                    ));
+                }
+
+                methods.insert(method.name.clone(), method.clone());
+            }
+        }
+
+        let breed = Breed::from(node.name.clone(), methods);
+        breed.borrow_mut().set_context(Some(context.clone()));
+        breed.borrow_mut().set_span(node.span.clone());
+
+        context
+            .borrow_mut()
+            .symbol_table
+            .borrow_mut()
+            .set(node.name.clone(), breed.clone());
+
+        result.success(breed)
+    }
+
+    fn visit_member_access_node(
+        &mut self,
+        node: &MemberAccessNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let object = result.register(self.visit(node.node_to_access, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let member = object.borrow().get_member(&node.member_name, &object);
+
+        match member {
+            Ok(member) => {
+                // prevent recursion issues by borrowing already borrowed objects
+                if let Ok(m) = &mut member.try_borrow_mut() {
+                    m.set_context(Some(context.clone()));
+                }
+
+                result.success(member)
+            }
+            Err(mut e) => {
+                e.span = node.span.clone();
+
+                result.failure(e)
+            }
+        }
+    }
+
+    fn visit_member_assign_node(
+        &mut self,
+        node: &MemberAssignNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        // constants are copied when accessed, so the copy would be changed instead of the constant
+        if let AstNode::VariableAccess(target) = arena.get(node.node_to_access)
+            && self.is_constant(&target.name, context.clone())
+        {
+            return result.failure(
+                StandardError::new("cannot change a constant value", node.span.clone(), None)
+                    .with_kind("ConstantError"),
+            );
+        }
+
+        let object = result.register(self.visit(node.node_to_access, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut value = result.register(self.visit(node.value_node, arena, context.clone()));
+
+        if result.should_return() {
+            return result;
+        }
+
+        if let Some(operator) = &node.operator {
+            let current = object.borrow().get_member(&node.member_name, &object);
+
+            match current
+                .and_then(|current| Self::compound_value(current, operator, value, &node.span))
+            {
+                Ok(new_value) => value = new_value,
+                Err(e) => return result.failure(e),
+            }
+        }
+
+        let assigned = object
+            .borrow_mut()
+            .set_member(&node.member_name, value.clone());
+
+        match assigned {
+            Ok(_) => result.success(value),
+            Err(e) => result.failure(e),
+        }
+    }
+
     fn visit_binary_operator_node(
         &mut self,
         node: &BinaryOperatorNode,
@@ -924,13 +2035,27 @@ impl Interpreter {
             return result;
         }
 
+        // 'and' and 'or' give back whichever operand decides the result, and skip the right one
+        // when the left one already decides it
+        if node.operator == "and" || node.operator == "or" {
+            if left.borrow().is_true() == (node.operator == "or") {
+                return result.success(left);
+            }
+
+            return self.visit(node.right_node, arena, context);
+        }
+
         let right = result.register(self.visit(node.right_node, &arena, context.clone()));
 
         if result.should_return() {
             return result;
         }
 
-        let operation_result = {
+        // comparisons only read the operands, so both can be borrowed at once even when one of
+        // them holds the other
+        let operation_result = if Value::is_comparison(&node.operator) {
+            left.borrow().compare(&node.operator, &right.borrow())
+        } else {
             let left_copy = left.borrow().clone();
             let mut left_borrow = left.borrow_mut();
 
@@ -968,21 +2093,18 @@ impl Interpreter {
 
         match node.operator.as_str() {
             "-1" => {
-                operation_result = value
-                    .borrow_mut()
-                    .perform_operation("*", Number::from(-1.0));
+                operation_result = value.borrow_mut().perform_operation("*", Number::from(-1));
             }
             "not" => {
-                operation_result = value
-                    .borrow_mut()
-                    .perform_operation("not", Number::false_value());
+                operation_result = Ok(Boolean::from(!value.borrow().is_true()));
             }
             _ => {
                 operation_result = Err(StandardError::new(
                     "unsupported unary operation",
                     value.borrow().span(),
                     None,
-                ))
+                )
+                .with_kind("TypeError"))
             }
         }
 
@@ -1006,18 +2128,65 @@ impl Interpreter {
         let value: Rc<RefCell<Value>>;
 
         if node.node_to_return.is_some() {
-            value = result.register(self.visit(node.node_to_return.unwrap(), &arena, context));
+            let node_to_return = node.node_to_return.unwrap();
+
+            // a function giving back a call to itself is run again in place by 'Function::execute',
+            // so recursion that works like a loop doesn't grow the stack
+            value = result.register(match arena.get(node_to_return) {
+                AstNode::Call(call_node) if self.tail_call_target.is_some() => {
+                    self.visit_call_node(call_node, arena, context, true)
+                }
+                _ => self.visit(node_to_return, &arena, context),
+            });
 
             if result.should_return() {
                 return result;
             }
         } else {
-            value = Number::null_value()
+            value = Null::null_value()
         }
 
         result.success_return(value)
     }
 
+    fn visit_yield_node(
+        &mut self,
+        node: &YieldNode,
+        arena: &AstArena,
+        context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let value = match node.node_to_yield {
+            Some(node_to_yield) => {
+                let value = result.register(self.visit(node_to_yield, arena, context));
+
+                if result.should_return() {
+                    return result;
+                }
+
+                value
+            }
+            None => Null::null_value(),
+        };
+
+        let Some(yielder) = self.yielder else {
+            return result.failure(StandardError::new(
+                "'toss' outside of a generator",
+                node.span.clone(),
+                None,
+            ));
+        };
+
+        // SAFETY: `yielder` is only ever set by `Iter::generator`, on the interpreter that is
+        // moved into the coroutine, and it points to the yielder that corosensei passes to that
+        // coroutine's body. `fork` and `run_module` never copy it to another interpreter, so it
+        // can only be read here while this interpreter runs the body on the coroutine's stack,
+        // and the yielder lives until the body returns
+        unsafe { (*yielder).suspend(value) };
+
+        result.success(Null::null_value())
+    }
+
     fn visit_continue_node(&mut self) -> RuntimeResult {
         RuntimeResult::new().success_continue()
     }
@@ -1026,6 +2195,39 @@ impl Interpreter {
         RuntimeResult::new().success_break()
     }
 
+    /// Returns the chain of files like 'a.glang → b.glang → a.glang' if the file is still loading,
+    /// because fetching it again would run it in a loop
+    fn import_cycle(&self, file_to_import: &Path, importing_path: &Path) -> Option<String> {
+        let loading_modules = self.loading_modules.borrow();
+
+        // the file that started the fetches is loading as well, even though nothing fetched it
+        let mut chain = match loading_modules.first() {
+            Some((_, span)) => vec![span.filename.as_path()],
+            None => vec![importing_path],
+        };
+        chain.extend(loading_modules.iter().map(|(path, _)| path.as_path()));
+
+        if chain.last() != Some(&importing_path) {
+            chain.push(importing_path);
+        }
+
+        let same_file = |a: &Path, b: &Path| {
+            a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+        };
+        let start = chain
+            .iter()
+            .position(|path| same_file(path, file_to_import))?;
+
+        Some(
+            chain[start..]
+                .iter()
+                .chain([&file_to_import])
+                .map(|path| path.file_name().unwrap_or_default().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" → "),
+        )
+    }
+
     fn resolve_import_path(
         &self,
         import_value: &Value,
@@ -1034,11 +2236,10 @@ impl Interpreter {
         let package_name = match import_value {
             Value::StringValue(v) => &v.value,
             _ => {
-                return Err(StandardError::new(
-                    "expected type string",
-                    span.clone(),
-                    None,
-                ));
+                return Err(
+                    StandardError::new("expected type string", span.clone(), None)
+                        .with_kind("TypeError"),
+                );
             }
         };
 
@@ -1051,20 +2252,18 @@ impl Interpreter {
             let registry = glang_tooling::read_registry();
 
             if let Some(pkg) = registry.packages.get(name) {
-                if version == "latest" {
-                    if let Some(latest_version) = get_latest_version(name) {
-                        if let Some(info) = pkg.get(&latest_version.to_string()) {
-                            if let Some(entry) = info.get("entry") {
-                                return Ok(PathBuf::from(entry));
-                            }
-                        }
-                    }
+                if version == "latest"
+                    && let Some(latest_version) = get_latest_version(name)
+                    && let Some(info) = pkg.get(&latest_version.to_string())
+                    && let Some(entry) = info.get("entry")
+                {
+                    return Ok(PathBuf::from(entry));
                 }
 
-                if let Some(info) = pkg.get(version) {
-                    if let Some(entry) = info.get("entry") {
-                        return Ok(PathBuf::from(entry));
-                    }
+                if let Some(info) = pkg.get(version)
+                    && let Some(entry) = info.get("entry")
+                {
+                    return Ok(PathBuf::from(entry));
                 }
             }
 
@@ -1072,7 +2271,8 @@ impl Interpreter {
                 "kennel or version not found",
                 span.clone(),
                 Some("kennels can be installed using 'glang install <kennel>'"),
-            ));
+            )
+            .with_kind("ImportError"));
         }
 
         // case 2: direct file
@@ -1085,18 +2285,50 @@ impl Interpreter {
                         "the specified import does not exist",
                         span.clone(),
                         None,
-                    ));
+                    )
+                    .with_kind("ImportError"));
                 }
             } else {
                 return Err(StandardError::new(
                     "the specified import is not a '.glang' file",
                     span.clone(),
                     None,
-                ));
+                )
+                .with_kind("ImportError"));
             }
         }
 
-        Err(StandardError::new("invalid import", span.clone(), None))
+        Err(StandardError::new("invalid import", span.clone(), None).with_kind("ImportError"))
+    }
+
+    /// Applies the operator of a compound assignment like '+=' to the current value of its target
+    ///
+    /// Lists and maps are changed in place by their operators, so the target keeps the same object
+    fn compound_value(
+        current: Rc<RefCell<Value>>,
+        operator: &str,
+        value: Rc<RefCell<Value>>,
+        span: &Span,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let is_collection = matches!(*current.borrow(), Value::ListValue(_) | Value::MapValue(_));
+
+        let operation_result = if Rc::ptr_eq(&current, &value) {
+            // like binary operators, an object combined with itself uses a clone of itself
+            let copy = current.borrow().clone();
+            current
+                .borrow_mut()
+                .perform_operation(operator, Rc::new(RefCell::new(copy)))?
+        } else {
+            current.borrow_mut().perform_operation(operator, value)?
+        };
+
+        if is_collection {
+            return Ok(current);
+        }
+
+        operation_result.borrow_mut().set_span(span.clone());
+
+        Ok(operation_result)
     }
 
     fn is_constant(&self, name: &str, context: Rc<RefCell<Context>>) -> bool {
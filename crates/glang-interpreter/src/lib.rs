@@ -6,6 +6,9 @@ mod values;
 
 pub use context::Context;
 pub use interpreter::{Interpreter, interpret};
-pub use runtime_result::RuntimeResult;
+pub use runtime_result::{RuntimeResult, TailCall};
 pub use symbol_table::SymbolTable;
-pub use values::{BuiltInFunction, Function, List, Number, Str, Value};
+pub use values::{
+    ArgumentDefault, Boolean, Breed, BuiltInFunction, Error, Function, Instance, Iter, List, Map,
+    Module, Null, Number, Parameters, Set, Str, Value, Yielder,
+};
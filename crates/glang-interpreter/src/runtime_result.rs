@@ -1,12 +1,22 @@
-use crate::{Number, Value};
+use crate::{Null, Value};
 use glang_attributes::StandardError;
 use std::{cell::RefCell, rc::Rc};
 
+/// A call a function makes to itself as the last thing it does, which is run in place of the
+/// current call so that the stack doesn't grow
+#[derive(Clone)]
+pub struct TailCall {
+    pub bound_self: Option<Rc<RefCell<Value>>>,
+    pub args: Vec<Rc<RefCell<Value>>>,
+    pub keyword_args: Vec<(String, Rc<RefCell<Value>>)>,
+}
+
 #[derive(Clone)]
 pub struct RuntimeResult {
     pub value: Rc<RefCell<Value>>,
     pub error: Option<StandardError>,
     pub func_return_value: Option<Rc<RefCell<Value>>>,
+    pub tail_call: Option<TailCall>,
     pub loop_should_continue: bool,
     pub loop_should_break: bool,
 }
@@ -14,18 +24,20 @@ pub struct RuntimeResult {
 impl RuntimeResult {
     pub fn new() -> Self {
         Self {
-            value: Number::null_value(),
+            value: Null::null_value(),
             error: None,
             func_return_value: None,
+            tail_call: None,
             loop_should_continue: false,
             loop_should_break: false,
         }
     }
 
     pub fn reset(&mut self) {
-        self.value = Number::null_value();
+        self.value = Null::null_value();
         self.error = None;
         self.func_return_value = None;
+        self.tail_call = None;
         self.loop_should_continue = false;
         self.loop_should_break = false;
     }
@@ -33,6 +45,7 @@ impl RuntimeResult {
     pub fn register(&mut self, result: RuntimeResult) -> Rc<RefCell<Value>> {
         self.error = result.error;
         self.func_return_value = result.func_return_value;
+        self.tail_call = result.tail_call;
         self.loop_should_continue = result.loop_should_continue;
         self.loop_should_break = result.loop_should_break;
 
@@ -53,6 +66,13 @@ impl RuntimeResult {
         self.clone()
     }
 
+    pub fn success_tail_call(&mut self, tail_call: TailCall) -> RuntimeResult {
+        self.reset();
+        self.tail_call = Some(tail_call);
+
+        self.clone()
+    }
+
     pub fn success_continue(&mut self) -> RuntimeResult {
         self.reset();
         self.loop_should_continue = true;
@@ -77,15 +97,8 @@ impl RuntimeResult {
     pub fn should_return(&self) -> bool {
         self.error.is_some()
             || self.func_return_value.is_some()
+            || self.tail_call.is_some()
             || self.loop_should_continue
             || self.loop_should_break
     }
-
-    pub fn should_propagate(&self) -> bool {
-        if let Some(err) = &self.error {
-            return err.error_propagates;
-        }
-
-        false
-    }
 }
@@ -32,16 +32,22 @@ impl SymbolTable {
             return;
         }
 
-        // otherwise try to update the parent scope
-        // if let Some(parent) = &self.parent {
-        //     if parent.borrow().get(&name).is_some() {
-        //         parent.borrow_mut().set(name, value);
+        self.symbols.insert(name, value);
+    }
 
-        //         return;
-        //     }
-        // }
+    /// Returns the closest enclosing table that defines the name, not counting this one
+    pub fn enclosing_scope(&self, name: &str) -> Option<Rc<RefCell<SymbolTable>>> {
+        let mut parent = self.parent.clone();
 
-        self.symbols.insert(name, value);
+        while let Some(table) = parent {
+            if table.borrow().symbols.contains_key(name) {
+                return Some(table);
+            }
+
+            parent = table.borrow().parent.clone();
+        }
+
+        None
     }
 
     pub fn remove(&mut self, name: &str) {
@@ -0,0 +1,64 @@
+use crate::{context::Context, values::value::Value};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct Boolean {
+    pub value: bool,
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+impl Boolean {
+    pub fn new(value: bool) -> Self {
+        Self {
+            value,
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(value: bool) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::BooleanValue(Boolean::new(value))))
+    }
+
+    pub fn true_value() -> Rc<RefCell<Value>> {
+        Boolean::from(true)
+    }
+
+    pub fn false_value() -> Rc<RefCell<Value>> {
+        Boolean::from(false)
+    }
+
+    pub fn perform_operation(
+        &self,
+        _operator: &str,
+        other: Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        // comparisons are handled by `Value`, and booleans don't support any other operator
+        Err(self.illegal_operation(Some(other)))
+    }
+
+    pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
+        let (pos_end, help_msg) = if let Some(illegal) = other {
+            (
+                illegal.borrow().position_end().clone(),
+                Some(format!(
+                    "the left type is a boolean and the right type is a {}",
+                    illegal.borrow().object_type()
+                )),
+            )
+        } else {
+            (self.span.end.clone(), None)
+        };
+
+        StandardError::new(
+            "operation not supported by type",
+            Span::new(&self.span.filename, self.span.start.clone(), pos_end),
+            help_msg.as_deref(),
+        )
+        .with_kind("TypeError")
+    }
+}
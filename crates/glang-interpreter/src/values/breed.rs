@@ -0,0 +1,191 @@
+use crate::{
+    context::Context,
+    interpreter::Interpreter,
+    runtime_result::RuntimeResult,
+    values::{function::Function, value::Value},
+};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct Breed {
+    pub name: String,
+    pub methods: Rc<HashMap<String, Function>>, // shared between the breed and all of its instances
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+/// Breeds are only equal to themselves, even if another breed has the same name
+impl PartialEq for Breed {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.methods, &other.methods)
+    }
+}
+
+impl Breed {
+    pub fn new(name: String, methods: HashMap<String, Function>) -> Self {
+        Self {
+            name,
+            methods: Rc::new(methods),
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(name: String, methods: HashMap<String, Function>) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::BreedValue(Breed::new(name, methods))))
+    }
+
+    /// Creates a new instance of the breed, passing the arguments to the `new` method if it exists
+    pub fn execute(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        keyword_args: &[(String, Rc<RefCell<Value>>)],
+        interpreter: &mut Interpreter,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        let instance = Instance::from(self.clone());
+        instance.borrow_mut().set_context(self.context.clone());
+        instance.borrow_mut().set_span(self.span.clone());
+
+        match self.methods.get("new") {
+            Some(constructor) => {
+                let constructor = constructor.bind(instance.clone());
+
+                if let Value::FunctionValue(ref constructor) = *constructor.borrow() {
+                    result.register(constructor.execute(args, keyword_args, interpreter));
+                }
+
+                if result.should_return() {
+                    return result;
+                }
+            }
+            None if !args.is_empty() || !keyword_args.is_empty() => {
+                return result.failure(StandardError::new(
+                    "invalid function call",
+                    self.span.clone(),
+                    Some(
+                        format!(
+                            "{} takes 0 arguments but the program gave {}, add a 'new' method to take arguments",
+                            self.name,
+                            args.len() + keyword_args.len()
+                        )
+                        .as_str(),
+                    ),
+                ).with_kind("ArgumentError"));
+            }
+            None => {}
+        }
+
+        result.success(instance)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub breed: Breed,
+    pub fields: Vec<(String, Rc<RefCell<Value>>)>, // kept in the order they were first set
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+impl PartialEq for Instance {
+    fn eq(&self, other: &Self) -> bool {
+        self.breed == other.breed
+            && self.fields.len() == other.fields.len()
+            && self.fields.iter().all(|(name, value)| {
+                match other
+                    .fields
+                    .iter()
+                    .find(|(other_name, _)| other_name == name)
+                {
+                    Some((_, other_value)) => *value.borrow() == *other_value.borrow(),
+                    None => false,
+                }
+            })
+    }
+}
+
+impl Instance {
+    pub fn new(breed: Breed) -> Self {
+        Self {
+            breed,
+            fields: Vec::new(),
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(breed: Breed) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::InstanceValue(Instance::new(breed))))
+    }
+
+    /// Returns the field with the given name, or the method bound to `this` if there is no such field
+    pub fn get_member(&self, name: &str, this: &Rc<RefCell<Value>>) -> Option<Rc<RefCell<Value>>> {
+        if let Some((_, value)) = self.fields.iter().find(|(field, _)| field == name) {
+            return Some(value.clone());
+        }
+
+        self.breed
+            .methods
+            .get(name)
+            .map(|method| method.bind(this.clone()))
+    }
+
+    pub fn set_member(
+        &mut self,
+        name: &str,
+        value: Rc<RefCell<Value>>,
+    ) -> Result<(), StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        match self.fields.iter_mut().find(|(field, _)| field == name) {
+            Some((_, field_value)) => *field_value = value,
+            None => self.fields.push((name.to_string(), value)),
+        }
+
+        Ok(())
+    }
+
+    pub fn perform_operation(
+        &self,
+        _operator: &str,
+        other: Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        // comparisons are handled by `Value`, and instances don't support any other operator
+        Err(self.illegal_operation(Some(other)))
+    }
+
+    pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
+        let (pos_end, help_msg) = if let Some(illegal) = other {
+            (
+                illegal.borrow().position_end().clone(),
+                Some(format!(
+                    "the left type is a {} and the right type is a {}",
+                    self.breed.name,
+                    illegal.borrow().object_type()
+                )),
+            )
+        } else {
+            (self.span.end.clone(), None)
+        };
+
+        StandardError::new(
+            "operation not supported by type",
+            Span::new(&self.span.filename, self.span.start.clone(), pos_end),
+            help_msg.as_deref(),
+        )
+        .with_kind("TypeError")
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    context::Context,
+    values::{list::List, number::Number, string::Str, value::Value},
+};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, rc::Rc};
+
+/// An error caught by a `catch` block
+#[derive(Debug, Clone)]
+pub struct Error {
+    pub error: StandardError, // kept whole, so that rethrowing it with 'uhoh' doesn't lose anything
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+/// Errors are equal when they have the same kind and message and were raised in the same place
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.error.kind == other.error.kind
+            && self.error.text == other.error.text
+            && self.error.span == other.error.span
+    }
+}
+
+impl Error {
+    pub fn new(error: StandardError) -> Self {
+        Self {
+            error,
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(error: StandardError) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::ErrorValue(Error::new(error))))
+    }
+
+    pub fn get_member(&self, name: &str) -> Option<Rc<RefCell<Value>>> {
+        let span = &self.error.span;
+
+        match name {
+            "kind" => Some(Str::from(&self.error.kind)),
+            "message" => Some(Str::from(&self.error.text)),
+            "file" => Some(Str::from(&span.filename.to_string_lossy())),
+            "line" => Some(Number::from(span.start.line_num)),
+            "column" => Some(Number::from(span.start.column_num)),
+            "trace" => Some(List::from(
+                self.error
+                    .trace
+                    .iter()
+                    .map(|frame| {
+                        Str::from(&format!(
+                            "{} ({}:{}:{})",
+                            frame.function_name,
+                            frame.span.filename.to_string_lossy(),
+                            frame.span.start.line_num,
+                            frame.span.start.column_num
+                        ))
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+}
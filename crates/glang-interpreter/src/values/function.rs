@@ -1,15 +1,22 @@
 use crate::{
-    List, Str,
+    Boolean, Iter, List, Map, Set, Str,
     context::Context,
     interpreter::Interpreter,
     runtime_result::RuntimeResult,
     symbol_table::SymbolTable,
-    values::{number::Number, value::Value},
+    values::{
+        null::Null,
+        number::{Number, NumberKind},
+        value::Value,
+    },
 };
 use glang_attributes::{Span, StandardError};
 use glang_parser::{AstArena, NodeID};
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
 use std::{
     cell::RefCell,
+    cmp::Ordering,
     env, fs,
     io::{Write, stdin, stdout},
     rc::Rc,
@@ -35,50 +42,322 @@ trait FunctionObject {
     ) -> RuntimeResult;
 }
 
+/// What an argument is set to when the call doesn't give it a value
+#[derive(Debug, Clone)]
+pub enum ArgumentDefault {
+    Node(NodeID), // evaluated on every call, so calls never share a default value
+    Value(Rc<RefCell<Value>>),
+}
+
+/// The arguments a function takes, like `(name, greeting = "hi", ...rest)`
+#[derive(Debug, Clone, Default)]
+pub struct Parameters {
+    pub names: Vec<String>,
+    pub defaults: Vec<Option<ArgumentDefault>>, // one for each name
+    pub rest_name: Option<String>,              // collects any extra arguments into a list
+}
+
+impl Parameters {
+    pub fn new(
+        names: Vec<String>,
+        defaults: Vec<Option<ArgumentDefault>>,
+        rest_name: Option<String>,
+    ) -> Self {
+        Self {
+            names,
+            defaults,
+            rest_name,
+        }
+    }
+
+    /// Creates parameters that must all be given, which is how most built-in functions work
+    pub fn positional(names: &[&str]) -> Self {
+        Self::new(
+            names.iter().map(|name| name.to_string()).collect(),
+            vec![None; names.len()],
+            None,
+        )
+    }
+
+    pub fn with_default(mut self, name: &str, value: Rc<RefCell<Value>>) -> Self {
+        self.names.push(name.to_string());
+        self.defaults.push(Some(ArgumentDefault::Value(value)));
+
+        self
+    }
+
+    pub fn with_rest(mut self, name: &str) -> Self {
+        self.rest_name = Some(name.to_string());
+
+        self
+    }
+
+    /// Returns every argument name in order, including the rest argument
+    pub fn all_names(&self) -> Vec<String> {
+        self.names
+            .iter()
+            .chain(self.rest_name.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// Matches the arguments of a call to these parameters
+    ///
+    /// The result has a value for each name in `all_names`, with `None` for arguments that
+    /// still need their default node evaluated. `bound` is the number of arguments that were
+    /// added automatically, like the instance of a method, so they aren't counted in errors
+    pub fn bind(
+        &self,
+        function_name: &str,
+        span: &Span,
+        bound: usize,
+        args: &[Rc<RefCell<Value>>],
+        keyword_args: &[(String, Rc<RefCell<Value>>)],
+    ) -> Result<Vec<Option<Rc<RefCell<Value>>>>, StandardError> {
+        let mut values: Vec<Option<Rc<RefCell<Value>>>> = vec![None; self.names.len()];
+        let mut rest = Vec::new();
+
+        for (i, arg) in args.iter().enumerate() {
+            if i < self.names.len() {
+                values[i] = Some(arg.clone());
+            } else if self.rest_name.is_some() {
+                rest.push(arg.clone());
+            } else {
+                let max = self.names.len() - bound;
+
+                return Err(StandardError::new(
+                    "invalid function call",
+                    span.clone(),
+                    Some(
+                        format!(
+                            "{} takes {}{} argument{} but the program gave {}",
+                            function_name,
+                            if self.defaults.iter().any(|default| default.is_some()) {
+                                "at most "
+                            } else {
+                                ""
+                            },
+                            max,
+                            if max == 1 { "" } else { "s" },
+                            args.len() - bound
+                        )
+                        .as_str(),
+                    ),
+                )
+                .with_kind("ArgumentError"));
+            }
+        }
+
+        for (name, value) in keyword_args {
+            let Some(i) = self.names.iter().position(|arg_name| arg_name == name) else {
+                return Err(StandardError::new(
+                    "invalid function call",
+                    value.borrow().span(),
+                    Some(format!("{function_name} has no argument named '{name}'").as_str()),
+                )
+                .with_kind("ArgumentError"));
+            };
+
+            if values[i].is_some() {
+                return Err(StandardError::new(
+                    "invalid function call",
+                    value.borrow().span(),
+                    Some(format!("the argument '{name}' was given more than once").as_str()),
+                )
+                .with_kind("ArgumentError"));
+            }
+
+            values[i] = Some(value.clone());
+        }
+
+        for (i, value) in values.iter_mut().enumerate() {
+            if value.is_some() {
+                continue;
+            }
+
+            match self.defaults[i] {
+                Some(ArgumentDefault::Value(ref default)) => *value = Some(default.clone()),
+                Some(ArgumentDefault::Node(_)) => {}
+                None => {
+                    return Err(StandardError::new(
+                        "invalid function call",
+                        span.clone(),
+                        Some(
+                            format!(
+                                "{} is missing the argument '{}'",
+                                function_name, self.names[i]
+                            )
+                            .as_str(),
+                        ),
+                    )
+                    .with_kind("ArgumentError"));
+                }
+            }
+        }
+
+        if self.rest_name.is_some() {
+            values.push(Some(List::from(rest)));
+        }
+
+        Ok(values)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Function {
     pub name: String,
     pub body_node: NodeID,
     pub arena: AstArena, // functions must own their own arena
-    pub arg_names: Rc<[String]>,
+    pub parameters: Rc<Parameters>,
     pub should_auto_return: bool,
+    pub is_generator: bool,
+    pub bound_self: Option<Rc<RefCell<Value>>>, // the instance passed as the first argument of a method
+    pub environment: Option<Rc<RefCell<SymbolTable>>>, // the scope the function was defined in
     pub context: Option<Rc<RefCell<Context>>>,
     pub is_const: bool,
     pub span: Span,
 }
 
+/// Functions are only equal to themselves, so two closures made by the same definition are
+/// different functions even when they print the same
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.parameters, &other.parameters)
+            && is_same_rc(&self.environment, &other.environment)
+            && is_same_rc(&self.bound_self, &other.bound_self)
+    }
+}
+
+fn is_same_rc<T>(left: &Option<Rc<T>>, right: &Option<Rc<T>>) -> bool {
+    match (left, right) {
+        (Some(left), Some(right)) => Rc::ptr_eq(left, right),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 impl Function {
     pub fn new(
         name: String,
         body_node: NodeID,
         arena: AstArena,
-        arg_names: &[String],
+        parameters: Parameters,
         should_auto_return: bool,
+        is_generator: bool,
     ) -> Self {
         Self {
             name,
             body_node,
             arena,
-            arg_names: Rc::from(arg_names),
+            parameters: Rc::new(parameters),
             should_auto_return,
+            is_generator,
+            bound_self: None,
+            environment: None,
             context: None,
             is_const: false,
             span: Span::empty(),
         }
     }
 
+    /// Returns the function with the scope it was defined in, which its calls can always see
+    ///
+    /// The context of a value changes to wherever it was last used, so a closure can't rely on it
+    pub fn with_environment(mut self, environment: Rc<RefCell<SymbolTable>>) -> Self {
+        self.environment = Some(environment);
+
+        self
+    }
+
+    /// Returns a copy of the function that always receives `instance` as its first argument
+    pub fn bind(&self, instance: Rc<RefCell<Value>>) -> Rc<RefCell<Value>> {
+        let mut method = self.clone();
+        method.bound_self = Some(instance);
+
+        Rc::new(RefCell::new(Value::FunctionValue(method)))
+    }
+
     pub fn execute(
         &self,
         args: &[Rc<RefCell<Value>>],
+        keyword_args: &[(String, Rc<RefCell<Value>>)],
+        interpreter: &mut Interpreter,
+    ) -> RuntimeResult {
+        let tail_call_target = interpreter
+            .tail_call_target
+            .replace(self.parameters.clone());
+        let mut result = self.execute_once(&self.bound_self, args, keyword_args, interpreter);
+
+        // a call to itself that the body gave back runs here, so it doesn't need another frame
+        while let Some(tail_call) = result.tail_call.take() {
+            result = self.execute_once(
+                &tail_call.bound_self,
+                &tail_call.args,
+                &tail_call.keyword_args,
+                interpreter,
+            );
+        }
+
+        interpreter.tail_call_target = tail_call_target;
+
+        result
+    }
+
+    fn execute_once(
+        &self,
+        bound_self: &Option<Rc<RefCell<Value>>>,
+        args: &[Rc<RefCell<Value>>],
+        keyword_args: &[(String, Rc<RefCell<Value>>)],
         interpreter: &mut Interpreter,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
         let exec_context = self.generate_new_context();
+        let args = match bound_self {
+            Some(instance) => [std::slice::from_ref(instance), args].concat(),
+            None => args.to_vec(),
+        };
+
+        let values = match self.parameters.bind(
+            &self.name,
+            &self.span,
+            bound_self.is_some() as usize,
+            &args,
+            keyword_args,
+        ) {
+            Ok(values) => values,
+            Err(error) => return result.failure(error),
+        };
+        let arg_names = self.parameters.all_names();
+
+        // arguments are set one at a time, so a default value can use the arguments before it
+        for (i, value) in values.into_iter().enumerate() {
+            let value = match (value, &self.parameters.defaults.get(i)) {
+                (Some(value), _) => value,
+                (None, Some(Some(ArgumentDefault::Node(default_node)))) => {
+                    let value = result.register(interpreter.visit(
+                        *default_node,
+                        &self.arena,
+                        exec_context.clone(),
+                    ));
 
-        result.register(self.check_and_populate_args(&self.arg_names, args, exec_context.clone()));
+                    if result.should_return() {
+                        return result;
+                    }
 
-        if result.should_return() {
-            return result;
+                    value
+                }
+                _ => unreachable!(),
+            };
+
+            self.populate_args(&arg_names[i..=i], &[value], exec_context.clone());
+        }
+
+        // the body of a generator only runs when a value is pulled from the returned iterator
+        if self.is_generator {
+            return match Iter::generator(self.clone(), exec_context, interpreter) {
+                Ok(generator) => result.success(generator),
+                Err(error) => result.failure(error),
+            };
         }
 
         let value =
@@ -94,7 +373,7 @@ impl Function {
             None
         }
         .or(result.func_return_value.clone())
-        .or(Some(Number::null_value()));
+        .or(Some(Null::null_value()));
 
         result.success(return_value.unwrap())
     }
@@ -102,7 +381,10 @@ impl Function {
 
 impl FunctionObject for Function {
     fn generate_new_context(&self) -> Rc<RefCell<Context>> {
-        let parent_st = self.context.as_ref().unwrap().borrow().symbol_table.clone();
+        let parent_st = match self.environment {
+            Some(ref environment) => environment.clone(),
+            None => self.context.as_ref().unwrap().borrow().symbol_table.clone(),
+        };
         let new_context = Context::new(
             Some(self.context.as_ref().unwrap().clone()),
             Some(self.span.clone()),
@@ -116,23 +398,29 @@ impl FunctionObject for Function {
         let mut result = RuntimeResult::new();
 
         if args.len() > arg_names.len() || args.len() < arg_names.len() {
-            return result.failure(StandardError::new(
-                "invalid function call",
-                self.span.clone(),
-                Some(
-                    format!(
-                        "{} takes {} argument{} but the program gave {}",
-                        self.name,
-                        arg_names.len(),
-                        if arg_names.len() > 1 { "s" } else { "" },
-                        args.len()
-                    )
-                    .as_str(),
-                ),
-            ));
+            // the bound instance isn't counted, because the program never passes it directly
+            let bound = self.bound_self.is_some() as usize;
+
+            return result.failure(
+                StandardError::new(
+                    "invalid function call",
+                    self.span.clone(),
+                    Some(
+                        format!(
+                            "{} takes {} argument{} but the program gave {}",
+                            self.name,
+                            arg_names.len() - bound,
+                            if arg_names.len() - bound > 1 { "s" } else { "" },
+                            args.len() - bound
+                        )
+                        .as_str(),
+                    ),
+                )
+                .with_kind("ArgumentError"),
+            );
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn populate_args(
@@ -169,22 +457,30 @@ impl FunctionObject for Function {
 
         self.populate_args(arg_names, args, expr_ctx);
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct BuiltInFunction {
     pub name: String,
+    pub bound_self: Option<Rc<RefCell<Value>>>, // the value a method like 'upper' was called on
     pub context: Option<Rc<RefCell<Context>>>,
     pub is_const: bool,
     pub span: Span,
 }
 
+impl PartialEq for BuiltInFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && is_same_rc(&self.bound_self, &other.bound_self)
+    }
+}
+
 impl BuiltInFunction {
     pub fn new(name: &str) -> Self {
         BuiltInFunction {
             name: name.to_string(),
+            bound_self: None,
             context: None,
             is_const: false,
             span: Span::empty(),
@@ -197,8 +493,90 @@ impl BuiltInFunction {
         ))))
     }
 
-    pub fn execute(&self, args: &[Rc<RefCell<Value>>]) -> RuntimeResult {
+    /// Returns the built-in method with the given name, bound to `receiver` as its first argument
+    pub fn method(name: &str, receiver: Rc<RefCell<Value>>) -> Rc<RefCell<Value>> {
+        let mut method = BuiltInFunction::new(name);
+        method.bound_self = Some(receiver);
+
+        Rc::new(RefCell::new(Value::BuiltInFunction(method)))
+    }
+
+    /// Returns whether this is a method bound to a map, whose names can overlap with list and
+    /// set methods
+    fn is_map_method(&self) -> bool {
+        matches!(self.bound_self, Some(ref receiver) if matches!(*receiver.borrow(), Value::MapValue(_)))
+    }
+
+    /// Returns the arguments that the built-in function or method takes
+    pub fn parameters(&self) -> Parameters {
+        match self.name.as_str() {
+            "bark" => Parameters::positional(&[]).with_rest("values"),
+            "chew" => Parameters::positional(&[]).with_default("msg", Str::from("")),
+            "dig" => Parameters::positional(&["file"]),
+            "bury" => Parameters::positional(&["file", "contents"]),
+            "copy" | "clear" | "tostring" | "tonumber" | "length" | "type" | "iter" => {
+                Parameters::positional(&["value"])
+            }
+            "uhoh" => Parameters::positional(&["msg"]).with_default("kind", Str::from("Error")),
+            "_env" => Parameters::positional(&["var"]),
+            "_now" => Parameters::positional(&[]),
+            "split" => Parameters::positional(&["str"]).with_default("pattern", Str::from(" ")),
+            "round" => Parameters::positional(&["num"]),
+            "range" => Parameters::positional(&["start"])
+                .with_default("end", Null::null_value())
+                .with_default("step", Number::from(1)),
+            "lines" => Parameters::positional(&["file"]),
+            "set" => Parameters::positional(&[]).with_default("values", Null::null_value()),
+            "upper" | "lower" | "trim" => Parameters::positional(&["str"]),
+            "replace" => Parameters::positional(&["str", "from", "to"]),
+            "starts_with" => Parameters::positional(&["str", "prefix"]),
+            "ends_with" => Parameters::positional(&["str", "suffix"]),
+            "contains" => Parameters::positional(&["value", "item"]),
+            "push" => Parameters::positional(&["list", "value"]),
+            "pop" | "reverse" | "sort" => Parameters::positional(&["list"]),
+            "insert" if self.is_map_method() => Parameters::positional(&["map", "key", "value"]),
+            "remove" if self.is_map_method() => Parameters::positional(&["map", "key"]),
+            "insert" => Parameters::positional(&["list", "index", "value"]),
+            "join" => Parameters::positional(&["list"]).with_default("separator", Str::from("")),
+            "keys" | "values" => Parameters::positional(&["map"]),
+            "add" | "remove" => Parameters::positional(&["set", "value"]),
+            "union" | "intersection" | "difference" => Parameters::positional(&["set", "other"]),
+            "to_list" => Parameters::positional(&["set"]),
+            "pull" => {
+                Parameters::positional(&["iterator"]).with_default("default", Null::null_value())
+            }
+            "take" => Parameters::positional(&["iterator", "count"]),
+            "collect" => Parameters::positional(&["iterator"]),
+            _ => panic!("CRITICAL ERROR: BUILT IN NAME IS NOT DEFINED"),
+        }
+    }
+
+    pub fn execute(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        keyword_args: &[(String, Rc<RefCell<Value>>)],
+    ) -> RuntimeResult {
         let exec_context = self.generate_new_context();
+        let args = match self.bound_self {
+            Some(ref receiver) => [std::slice::from_ref(receiver), args].concat(),
+            None => args.to_vec(),
+        };
+
+        // built-in defaults are always values, so every argument is set after binding
+        let args: &[Rc<RefCell<Value>>] = &match self.parameters().bind(
+            &self.name,
+            &self.span,
+            self.bound_self.is_some() as usize,
+            &args,
+            keyword_args,
+        ) {
+            Ok(values) => values.into_iter().flatten().collect::<Vec<_>>(),
+            Err(error) => return RuntimeResult::new().failure(error),
+        };
+
+        if self.bound_self.is_some() {
+            return self.execute_method(args, exec_context);
+        }
 
         match self.name.as_str() {
             "bark" => self.execute_print(args, exec_context),
@@ -216,6 +594,10 @@ impl BuiltInFunction {
             "_now" => self.execute_now(args, exec_context),
             "split" => self.execute_split(args, exec_context),
             "round" => self.execute_round(args, exec_context),
+            "iter" => self.execute_iter(args, exec_context),
+            "range" => self.execute_range(args, exec_context),
+            "lines" => self.execute_lines(args, exec_context),
+            "set" => self.execute_set(args, exec_context),
             _ => panic!("CRITICAL ERROR: BUILT IN NAME IS NOT DEFINED"),
         }
     }
@@ -226,15 +608,24 @@ impl BuiltInFunction {
         exec_ctx: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_args(&["values".to_string()], args, exec_ctx));
 
         if result.should_return() {
             return result;
         }
 
-        println!("{}", args[0].borrow().as_string());
+        if let Value::ListValue(ref values) = *args[0].borrow() {
+            let output = values
+                .elements
+                .iter()
+                .map(|value| value.borrow().as_string())
+                .collect::<Vec<_>>()
+                .join(" ");
 
-        result.success(Number::null_value())
+            println!("{output}");
+        }
+
+        result.success(Null::null_value())
     }
 
     pub fn execute_input(
@@ -254,11 +645,14 @@ impl BuiltInFunction {
         let message = match *message_arg.borrow() {
             Value::StringValue(ref string) => string.value.clone(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    message_arg.borrow().span(),
-                    Some("add a message like 'Enter a number:' to get user input"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        message_arg.borrow().span(),
+                        Some("add a message like 'Enter a number:' to get user input"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
@@ -294,20 +688,26 @@ impl BuiltInFunction {
         let filename = match *file_arg.borrow() {
             Value::StringValue(ref string) => string.value.clone(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    file_arg.borrow().span(),
-                    Some("add a filename to read like 'test.txt'"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        file_arg.borrow().span(),
+                        Some("add a filename to read like 'test.txt'"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
         if fs::exists(&filename).is_err() {
-            return result.failure(StandardError::new(
-                "file doesn't exist",
-                file_arg.borrow().span(),
-                Some("add a filename to read like 'test.txt'"),
-            ));
+            return result.failure(
+                StandardError::new(
+                    "file doesn't exist",
+                    file_arg.borrow().span(),
+                    Some("add a filename to read like 'test.txt'"),
+                )
+                .with_kind("FileError"),
+            );
         }
 
         let mut contents = String::new();
@@ -315,15 +715,18 @@ impl BuiltInFunction {
         match fs::read_to_string(&filename) {
             Ok(extra) => contents.push_str(&extra),
             Err(_) => {
-                return result.failure(StandardError::new(
-                    "file contents couldn't be read properly",
-                    file_arg.borrow().span(),
-                    Some("add a UTF-8 encoded file to read"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "file contents couldn't be read properly",
+                        file_arg.borrow().span(),
+                        Some("add a UTF-8 encoded file to read"),
+                    )
+                    .with_kind("FileError"),
+                );
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     pub fn execute_write(
@@ -348,37 +751,46 @@ impl BuiltInFunction {
         let filename = match *file_arg.borrow() {
             Value::StringValue(ref string) => string.value.clone(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    file_arg.borrow().span(),
-                    Some("add a filename to write to like 'test.txt'"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        file_arg.borrow().span(),
+                        Some("add a filename to write to like 'test.txt'"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
         let contents = match *contents_arg.borrow() {
             Value::StringValue(ref string) => string.value.clone(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    file_arg.borrow().span(),
-                    Some("add the file contents to write into the file"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        file_arg.borrow().span(),
+                        Some("add the file contents to write into the file"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
         match fs::write(&filename, &contents) {
             Ok(_) => {}
             Err(_) => {
-                return result.failure(StandardError::new(
-                    "file contents couldn't be written properly",
-                    file_arg.borrow().span(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "file contents couldn't be written properly",
+                        file_arg.borrow().span(),
+                        None,
+                    )
+                    .with_kind("FileError"),
+                );
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     pub fn execute_copy(
@@ -415,17 +827,18 @@ impl BuiltInFunction {
 
         match *object_arg.borrow_mut() {
             Value::ListValue(ref mut v) => v.elements.clear(),
+            Value::MapValue(ref mut v) => v.entries.clear(),
+            Value::SetValue(ref mut v) => *v = Set::new(),
             Value::StringValue(ref mut v) => v.value.clear(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type list or string",
-                    span,
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new("expected type list, map, set or string", span, None)
+                        .with_kind("TypeError"),
+                );
             }
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     pub fn execute_tostring(
@@ -457,23 +870,29 @@ impl BuiltInFunction {
 
         let string_to_convert = args[0].clone();
 
-        let value: f64 = match *string_to_convert.borrow() {
-            Value::StringValue(ref string) => match string.value.clone().parse() {
-                Ok(number) => number,
-                Err(e) => {
-                    return result.failure(StandardError::new(
-                        format!("string couldn't be converted to number {e}").as_str(),
-                        string_to_convert.borrow().span(),
-                        Some("ensure the string is represented as a valid number like '1.0'"),
-                    ));
+        let value = match *string_to_convert.borrow() {
+            Value::StringValue(ref string) => match Number::parse(&string.value) {
+                Some(number) => number,
+                None => {
+                    return result.failure(
+                        StandardError::new(
+                            "string couldn't be converted to number",
+                            string_to_convert.borrow().span(),
+                            Some("ensure the string is represented as a valid number like '1.0'"),
+                        )
+                        .with_kind("ValueError"),
+                    );
                 }
             },
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    string_to_convert.borrow().span(),
-                    Some("add a string like '1.0' to convert to a number object"),
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        string_to_convert.borrow().span(),
+                        Some("add a string like '1.0' to convert to a number object"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
@@ -494,15 +913,20 @@ impl BuiltInFunction {
 
         let object_arg = args[0].clone();
 
-        let length: f64 = match *object_arg.borrow() {
-            Value::StringValue(ref value) => value.value.len() as f64,
-            Value::ListValue(ref value) => value.elements.len() as f64,
+        let length = match *object_arg.borrow() {
+            Value::StringValue(ref value) => value.value.len(),
+            Value::ListValue(ref value) => value.elements.len(),
+            Value::MapValue(ref value) => value.entries.len(),
+            Value::SetValue(ref value) => value.elements.len(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string or list",
-                    object_arg.borrow().span(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new(
+                        "expected type string, list, map or set",
+                        object_arg.borrow().span(),
+                        None,
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
@@ -515,7 +939,11 @@ impl BuiltInFunction {
         exec_ctx: Rc<RefCell<Context>>,
     ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
-        result.register(self.check_and_populate_args(&["msg".to_string()], args, exec_ctx));
+        result.register(self.check_and_populate_args(
+            &["msg".to_string(), "kind".to_string()],
+            args,
+            exec_ctx,
+        ));
 
         if result.should_return() {
             return result;
@@ -523,24 +951,34 @@ impl BuiltInFunction {
 
         let error = args[0].clone();
 
-        let message = match *error.borrow() {
-            Value::StringValue(_) => error.clone(),
-            _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    error.borrow().span(),
-                    Some("add an error message"),
-                ));
+        let error = match (&*error.borrow(), &*args[1].borrow()) {
+            // a caught error is thrown again exactly as it was
+            (Value::ErrorValue(caught), _) => caught.error.clone(),
+            (Value::StringValue(message), Value::StringValue(kind)) => {
+                StandardError::new(&message.value, self.span.clone(), None).with_kind(&kind.value)
+            }
+            (Value::StringValue(_), kind) => {
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        kind.span(),
+                        Some("add an error kind like 'ValueError'"),
+                    )
+                    .with_kind("TypeError"),
+                );
+            }
+            (message, _) => {
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        message.span(),
+                        Some("add an error message"),
+                    )
+                    .with_kind("TypeError"),
+                );
             }
         };
 
-        let mut error = StandardError::new(
-            message.borrow().as_string().as_str(),
-            message.borrow().span(),
-            None,
-        );
-        error.error_propagates = true;
-
         result.failure(error)
     }
 
@@ -556,7 +994,7 @@ impl BuiltInFunction {
             return result;
         }
 
-        result.success(Str::from(&args[0].borrow().object_type().to_string()))
+        result.success(Str::from(args[0].borrow().object_type()))
     }
 
     pub fn execute_env(
@@ -576,11 +1014,10 @@ impl BuiltInFunction {
         let variable = match *env_arg.borrow() {
             Value::StringValue(ref var) => var.value.clone(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    env_arg.borrow().span(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new("expected type string", env_arg.borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
             }
         };
 
@@ -606,9 +1043,9 @@ impl BuiltInFunction {
             return result;
         }
 
-        result.success(Number::from(
-            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis() as f64,
-        ))
+        result.success(Number::from(BigInt::from(
+            std::time::UNIX_EPOCH.elapsed().unwrap().as_millis(),
+        )))
     }
 
     pub fn execute_split(
@@ -634,14 +1071,13 @@ impl BuiltInFunction {
             (Value::StringValue(input), Value::StringValue(pat)) => input
                 .value
                 .split(&pat.value)
-                .map(|s| Str::from(s))
+                .map(Str::from)
                 .collect::<Vec<_>>(),
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type string",
-                    string.borrow().span(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new("expected type string", string.borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
             }
         };
 
@@ -663,51 +1099,939 @@ impl BuiltInFunction {
         let number = args[0].clone();
 
         match *number.borrow() {
-            Value::NumberValue(ref num) => result.success(Number::from(num.value.round())),
+            Value::NumberValue(ref num) => match num.value {
+                NumberKind::Float(value) => match BigInt::from_f64(value.round()) {
+                    Some(rounded) => result.success(Number::from(rounded)),
+                    None => result.success(Number::from(value)), // infinity and NaN can't be rounded
+                },
+                NumberKind::Integer(_) => result.success(Number::from(num.value.clone())),
+            },
             _ => {
-                return result.failure(StandardError::new(
-                    "expected type number",
-                    number.borrow().span(),
-                    None,
-                ));
+                return result.failure(
+                    StandardError::new("expected type number", number.borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
             }
         }
     }
-}
+    pub fn execute_iter(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["value".to_string()], args, exec_ctx));
 
-impl FunctionObject for BuiltInFunction {
-    fn generate_new_context(&self) -> Rc<RefCell<Context>> {
-        let parent_st = self.context.as_ref().unwrap().borrow().symbol_table.clone();
-        let new_context = Context::new(
-            Some(self.context.as_ref().unwrap().clone()),
-            Some(self.span.clone()),
-            Rc::new(RefCell::new(SymbolTable::new(Some(parent_st)))),
-        );
+        if result.should_return() {
+            return result;
+        }
 
-        Rc::new(RefCell::new(new_context))
+        match Iter::from_value(&args[0]) {
+            Ok(iterator) => result.success(iterator),
+            Err(error) => result.failure(error),
+        }
     }
 
-    fn check_args(&self, arg_names: &[String], args: &[Rc<RefCell<Value>>]) -> RuntimeResult {
+    pub fn execute_range(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
         let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["start".to_string(), "end".to_string(), "step".to_string()],
+            args,
+            exec_ctx,
+        ));
 
-        if args.len() > arg_names.len() || args.len() < arg_names.len() {
-            return result.failure(StandardError::new(
-                "invalid function call",
-                self.span.clone(),
-                Some(
-                    format!(
-                        "{} takes {} argument{} but the program gave {}",
-                        self.name,
-                        arg_names.len(),
-                        if arg_names.len() > 1 { "s" } else { "" },
-                        args.len()
+        if result.should_return() {
+            return result;
+        }
+
+        let mut bounds = Vec::new();
+
+        for arg in args {
+            match *arg.borrow() {
+                Value::NumberValue(ref number) => bounds.push(Some(number.value.clone())),
+                Value::NullValue(_) => bounds.push(None),
+                ref other => {
+                    return result.failure(
+                        StandardError::new("expected type number", other.span(), None)
+                            .with_kind("TypeError"),
+                    );
+                }
+            }
+        }
+
+        // with only one number, the range counts from zero up to that number
+        let (start, end) = match (bounds[0].clone(), bounds[1].clone()) {
+            (Some(start), Some(end)) => (start, end),
+            (Some(end), None) => (NumberKind::from(0), end),
+            (None, _) => {
+                return result.failure(
+                    StandardError::new("expected type number", args[0].borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
+            }
+        };
+        let step = match bounds[2].clone() {
+            Some(step) if step.is_zero() => {
+                return result.failure(
+                    StandardError::new("range step cannot be zero", args[2].borrow().span(), None)
+                        .with_kind("ValueError"),
+                );
+            }
+            Some(step) => step,
+            None => NumberKind::from(1),
+        };
+
+        result.success(Iter::range(start, end, step))
+    }
+
+    pub fn execute_lines(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["file".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let file_arg = args[0].clone();
+
+        let filename = match *file_arg.borrow() {
+            Value::StringValue(ref string) => string.value.clone(),
+            _ => {
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        file_arg.borrow().span(),
+                        Some("add a filename to read like 'test.txt'"),
                     )
-                    .as_str(),
-                ),
-            ));
+                    .with_kind("TypeError"),
+                );
+            }
+        };
+
+        match fs::File::open(&filename) {
+            Ok(file) => result.success(Iter::lines(file)),
+            Err(_) => result.failure(
+                StandardError::new(
+                    "file doesn't exist",
+                    file_arg.borrow().span(),
+                    Some("add a filename to read like 'test.txt'"),
+                )
+                .with_kind("FileError"),
+            ),
+        }
+    }
+
+    pub fn execute_set(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["values".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut values = Vec::new();
+
+        // without an argument the set starts out empty, otherwise it takes the values of an iterable
+        if !matches!(*args[0].borrow(), Value::NullValue(_)) {
+            let iterator = match Iter::from_value(&args[0]) {
+                Ok(iterator) => iterator,
+                Err(error) => return result.failure(error),
+            };
+            let iterator = match *iterator.borrow() {
+                Value::IteratorValue(ref iterator) => iterator.clone(),
+                _ => unreachable!(),
+            };
+
+            loop {
+                match iterator.next() {
+                    Ok(Some(value)) => values.push(value),
+                    Ok(None) => break,
+                    Err(error) => return result.failure(error),
+                }
+            }
+        }
+
+        match Set::from(&values) {
+            Ok(set) => result.success(set),
+            Err(error) => result.failure(error),
+        }
+    }
+
+    pub fn execute_method(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_context: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        match self.name.as_str() {
+            "length" => self.execute_length(args, exec_context),
+            "split" => self.execute_split(args, exec_context),
+            "upper" => self.execute_upper(args, exec_context),
+            "lower" => self.execute_lower(args, exec_context),
+            "trim" => self.execute_trim(args, exec_context),
+            "replace" => self.execute_replace(args, exec_context),
+            "starts_with" => self.execute_starts_with(args, exec_context),
+            "ends_with" => self.execute_ends_with(args, exec_context),
+            "contains" => self.execute_contains(args, exec_context),
+            "push" => self.execute_push(args, exec_context),
+            "pop" => self.execute_pop(args, exec_context),
+            "insert" if self.is_map_method() => self.execute_map_insert(args, exec_context),
+            "remove" if self.is_map_method() => self.execute_map_remove(args, exec_context),
+            "insert" => self.execute_insert(args, exec_context),
+            "join" => self.execute_join(args, exec_context),
+            "reverse" => self.execute_reverse(args, exec_context),
+            "sort" => self.execute_sort(args, exec_context),
+            "keys" => self.execute_keys(args, exec_context),
+            "values" => self.execute_values(args, exec_context),
+            "add" => self.execute_add(args, exec_context),
+            "remove" => self.execute_remove(args, exec_context),
+            "union" | "intersection" | "difference" => {
+                self.execute_set_operation(args, exec_context)
+            }
+            "to_list" => self.execute_to_list(args, exec_context),
+            "pull" => self.execute_pull(args, exec_context),
+            "take" => self.execute_take(args, exec_context),
+            "collect" => self.execute_collect(args, exec_context),
+            _ => panic!("CRITICAL ERROR: BUILT IN METHOD IS NOT DEFINED"),
+        }
+    }
+
+    pub fn execute_upper(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["str".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        result.success(Str::from(&args[0].borrow().as_string().to_uppercase()))
+    }
+
+    pub fn execute_lower(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["str".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        result.success(Str::from(&args[0].borrow().as_string().to_lowercase()))
+    }
+
+    pub fn execute_trim(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["str".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        result.success(Str::from(args[0].borrow().as_string().trim()))
+    }
+
+    pub fn execute_replace(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["str".to_string(), "from".to_string(), "to".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let replaced = match (&*args[0].borrow(), &*args[1].borrow(), &*args[2].borrow()) {
+            (Value::StringValue(input), Value::StringValue(from), Value::StringValue(to)) => {
+                input.value.replace(&from.value, &to.value)
+            }
+            _ => {
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        self.span.clone(),
+                        Some("replace takes two strings like 'name.replace(\"a\", \"b\")'"),
+                    )
+                    .with_kind("TypeError"),
+                );
+            }
+        };
+
+        result.success(Str::from(&replaced))
+    }
+
+    pub fn execute_starts_with(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["str".to_string(), "prefix".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let prefix = args[1].clone();
+
+        let starts_with = match *prefix.borrow() {
+            Value::StringValue(ref prefix) => {
+                args[0].borrow().as_string().starts_with(&prefix.value)
+            }
+            _ => {
+                return result.failure(
+                    StandardError::new("expected type string", prefix.borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
+            }
+        };
+
+        result.success(Boolean::from(starts_with))
+    }
+
+    pub fn execute_ends_with(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["str".to_string(), "suffix".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let suffix = args[1].clone();
+
+        let ends_with = match *suffix.borrow() {
+            Value::StringValue(ref suffix) => args[0].borrow().as_string().ends_with(&suffix.value),
+            _ => {
+                return result.failure(
+                    StandardError::new("expected type string", suffix.borrow().span(), None)
+                        .with_kind("TypeError"),
+                );
+            }
+        };
+
+        result.success(Boolean::from(ends_with))
+    }
+
+    pub fn execute_contains(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["value".to_string(), "item".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let item = args[1].clone();
+
+        let contains = match (&*args[0].borrow(), &*item.borrow()) {
+            (Value::StringValue(input), Value::StringValue(item)) => {
+                input.value.contains(&item.value)
+            }
+            (Value::StringValue(_), _) => {
+                return result.failure(
+                    StandardError::new(
+                        "expected type string",
+                        item.borrow().span(),
+                        Some("strings can only contain other strings"),
+                    )
+                    .with_kind("TypeError"),
+                );
+            }
+            (Value::ListValue(list), item) => list
+                .elements
+                .iter()
+                .any(|element| *element.borrow() == *item),
+            (Value::MapValue(map), item) => map.get(item).is_some(),
+            (Value::SetValue(set), item) => item.hash_key().is_some_and(|key| set.contains(&key)),
+            _ => unreachable!(),
+        };
+
+        result.success(Boolean::from(contains))
+    }
+
+    pub fn execute_push(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow_mut() {
+            Value::ListValue(ref mut list) => result.success(list.push(args[1].clone())),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_pop(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["list".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow_mut() {
+            Value::ListValue(ref mut list) => match list.elements.pop() {
+                Some(element) => result.success(element),
+                None => result.failure(
+                    StandardError::new("cannot pop from an empty list", self.span.clone(), None)
+                        .with_kind("IndexError"),
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_insert(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "index".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let index = match *args[1].borrow() {
+            Value::NumberValue(ref index) => index.clone(),
+            ref other => {
+                return result.failure(
+                    StandardError::new("expected type number", other.span(), None)
+                        .with_kind("TypeError"),
+                );
+            }
+        };
+
+        match *args[0].borrow_mut() {
+            Value::ListValue(ref mut list) => {
+                // the length is one more than the list so values can also be inserted at the end
+                match index.as_index(list.elements.len() + 1) {
+                    Ok(index) => {
+                        list.elements.insert(index, args[2].clone());
+
+                        result.success(Null::null_value())
+                    }
+                    Err(e) => result.failure(e),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_join(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["list".to_string(), "separator".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let joined = match (&*args[0].borrow(), &*args[1].borrow()) {
+            (Value::ListValue(list), Value::StringValue(separator)) => list
+                .elements
+                .iter()
+                .map(|element| element.borrow().as_string())
+                .collect::<Vec<_>>()
+                .join(&separator.value),
+            (_, separator) => {
+                return result.failure(
+                    StandardError::new("expected type string", separator.span(), None)
+                        .with_kind("TypeError"),
+                );
+            }
+        };
+
+        result.success(Str::from(&joined))
+    }
+
+    pub fn execute_reverse(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["list".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow_mut() {
+            Value::ListValue(ref mut list) => result.success(list.reverse()),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_sort(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["list".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let mut elements = match *args[0].borrow() {
+            Value::ListValue(ref list) => list.elements.clone(),
+            _ => unreachable!(),
+        };
+        let mut is_ordered = true;
+
+        // elements are sorted with the same ordering as '<', and the list is left alone if any
+        // two of them can't be compared
+        elements.sort_by(|a, b| {
+            a.borrow().partial_cmp(&b.borrow()).unwrap_or_else(|| {
+                is_ordered = false;
+                Ordering::Equal
+            })
+        });
+
+        if !is_ordered {
+            return result.failure(
+                StandardError::new(
+                    "list elements can't be ordered",
+                    args[0].borrow().span(),
+                    Some("only numbers, strings, booleans and lists of them can be sorted"),
+                )
+                .with_kind("TypeError"),
+            );
+        }
+
+        match *args[0].borrow_mut() {
+            Value::ListValue(ref mut list) => {
+                list.elements = elements;
+                result.success(Null::null_value())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_keys(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["map".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow() {
+            Value::MapValue(ref map) => result.success(List::from(map.keys())),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_values(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["map".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow() {
+            Value::MapValue(ref map) => result.success(List::from(
+                map.entries.iter().map(|(_, value)| value.clone()).collect(),
+            )),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_map_insert(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["map".to_string(), "key".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        // the key is checked before the map is borrowed, so a map can't be its own key
+        if let Err(error) = Map::check_key(&args[1]) {
+            return result.failure(error);
+        }
+
+        match *args[0].borrow_mut() {
+            Value::MapValue(ref mut map) => match map.set_index(&args[1], args[2].clone()) {
+                Ok(()) => result.success(Null::null_value()),
+                Err(error) => result.failure(error),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_map_remove(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["map".to_string(), "key".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        // the key is checked before the map is borrowed, so a map can't be its own key
+        if let Err(error) = Map::check_key(&args[1]) {
+            return result.failure(error);
+        }
+
+        match *args[0].borrow_mut() {
+            Value::MapValue(ref mut map) => match map.remove_index(&args[1]) {
+                Ok(value) => result.success(value),
+                Err(error) => result.failure(error),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_add(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["set".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow_mut() {
+            Value::SetValue(ref mut set) => match set.insert(&args[1]) {
+                Ok(added) => result.success(Boolean::from(added)),
+                Err(error) => result.failure(error),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_remove(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["set".to_string(), "value".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow_mut() {
+            Value::SetValue(ref mut set) => match set.remove(&args[1]) {
+                Ok(removed) => result.success(Boolean::from(removed)),
+                Err(error) => result.failure(error),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_set_operation(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["set".to_string(), "other".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let other = args[1].borrow();
+
+        let Value::SetValue(ref other) = *other else {
+            return result.failure(
+                StandardError::new(
+                    "expected type set",
+                    other.span(),
+                    Some(format!("the object is a {}", other.object_type()).as_str()),
+                )
+                .with_kind("TypeError"),
+            );
+        };
+
+        match (&*args[0].borrow(), self.name.as_str()) {
+            (Value::SetValue(set), "union") => result.success(set.union(other)),
+            (Value::SetValue(set), "intersection") => result.success(set.intersection(other)),
+            (Value::SetValue(set), "difference") => result.success(set.difference(other)),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_to_list(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["set".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        match *args[0].borrow() {
+            Value::SetValue(ref set) => result.success(set.to_list()),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn execute_pull(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["iterator".to_string(), "default".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        // the iterator is copied out first, so a generator can still use the value while it runs
+        let iterator = match *args[0].borrow() {
+            Value::IteratorValue(ref iterator) => iterator.clone(),
+            _ => unreachable!(),
+        };
+
+        match iterator.next() {
+            Ok(Some(value)) => result.success(value),
+            Ok(None) => result.success(args[1].clone()),
+            Err(error) => result.failure(error),
+        }
+    }
+
+    pub fn execute_take(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(
+            &["iterator".to_string(), "count".to_string()],
+            args,
+            exec_ctx,
+        ));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let iterator = match *args[0].borrow() {
+            Value::IteratorValue(ref iterator) => iterator.clone(),
+            _ => unreachable!(),
+        };
+        let count = match *args[1].borrow() {
+            Value::NumberValue(Number {
+                value: NumberKind::Integer(ref count),
+                ..
+            }) if !count.is_negative() => count.to_usize().unwrap_or(usize::MAX),
+            ref other => {
+                return result.failure(
+                    StandardError::new(
+                        "expected a whole number that isn't negative",
+                        other.span(),
+                        None,
+                    )
+                    .with_kind("ValueError"),
+                );
+            }
+        };
+        let mut elements = Vec::new();
+
+        while elements.len() < count {
+            match iterator.next() {
+                Ok(Some(value)) => elements.push(value),
+                Ok(None) => break,
+                Err(error) => return result.failure(error),
+            }
+        }
+
+        result.success(List::from(elements))
+    }
+
+    pub fn execute_collect(
+        &self,
+        args: &[Rc<RefCell<Value>>],
+        exec_ctx: Rc<RefCell<Context>>,
+    ) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+        result.register(self.check_and_populate_args(&["iterator".to_string()], args, exec_ctx));
+
+        if result.should_return() {
+            return result;
+        }
+
+        let iterator = match *args[0].borrow() {
+            Value::IteratorValue(ref iterator) => iterator.clone(),
+            _ => unreachable!(),
+        };
+        let mut elements = Vec::new();
+
+        loop {
+            match iterator.next() {
+                Ok(Some(value)) => elements.push(value),
+                Ok(None) => break,
+                Err(error) => return result.failure(error),
+            }
+        }
+
+        result.success(List::from(elements))
+    }
+}
+
+impl FunctionObject for BuiltInFunction {
+    fn generate_new_context(&self) -> Rc<RefCell<Context>> {
+        let parent_st = self.context.as_ref().unwrap().borrow().symbol_table.clone();
+        let new_context = Context::new(
+            Some(self.context.as_ref().unwrap().clone()),
+            Some(self.span.clone()),
+            Rc::new(RefCell::new(SymbolTable::new(Some(parent_st)))),
+        );
+
+        Rc::new(RefCell::new(new_context))
+    }
+
+    fn check_args(&self, arg_names: &[String], args: &[Rc<RefCell<Value>>]) -> RuntimeResult {
+        let mut result = RuntimeResult::new();
+
+        if args.len() > arg_names.len() || args.len() < arg_names.len() {
+            // the receiver of a method isn't counted, because the program never passes it directly
+            let bound = self.bound_self.is_some() as usize;
+
+            return result.failure(
+                StandardError::new(
+                    "invalid function call",
+                    self.span.clone(),
+                    Some(
+                        format!(
+                            "{} takes {} argument{} but the program gave {}",
+                            self.name,
+                            arg_names.len() - bound,
+                            if arg_names.len() - bound > 1 { "s" } else { "" },
+                            args.len() - bound
+                        )
+                        .as_str(),
+                    ),
+                )
+                .with_kind("ArgumentError"),
+            );
         }
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 
     fn populate_args(
@@ -744,6 +2068,6 @@ impl FunctionObject for BuiltInFunction {
 
         self.populate_args(arg_names, args, expr_ctx);
 
-        result.success(Number::null_value())
+        result.success(Null::null_value())
     }
 }
@@ -0,0 +1,237 @@
+use crate::{
+    context::Context,
+    interpreter::Interpreter,
+    values::{
+        function::Function,
+        number::{Number, NumberKind},
+        string::Str,
+        value::Value,
+    },
+};
+use corosensei::{
+    Coroutine, CoroutineResult,
+    stack::{DefaultStack, Stack},
+};
+use glang_attributes::{Span, StandardError};
+use std::{
+    cell::RefCell,
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader, Lines},
+    rc::Rc,
+    vec,
+};
+
+/// The handle a generator uses to pause itself and hand a value back to the loop pulling from it
+pub type Yielder = corosensei::Yielder<(), Rc<RefCell<Value>>>;
+
+// generators get a small stack of their own, since a program can keep many of them alive at once,
+// and deep recursion inside of one is stopped by the stack check in 'visit'
+const GENERATOR_STACK_SIZE: usize = 1024 * 1024;
+
+/// Where the values of an iterator come from
+enum Source {
+    Elements(vec::IntoIter<Rc<RefCell<Value>>>),
+    Chars {
+        value: String,
+        offset: usize,
+    },
+    Range {
+        current: NumberKind,
+        end: NumberKind,
+        step: NumberKind,
+    },
+    Lines(Lines<BufReader<File>>),
+    Generator(Coroutine<(), Rc<RefCell<Value>>, Result<(), StandardError>, DefaultStack>),
+    Finished,
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Elements(_) => write!(f, "Elements"),
+            Source::Chars { .. } => write!(f, "Chars"),
+            Source::Range { .. } => write!(f, "Range"),
+            Source::Lines(_) => write!(f, "Lines"),
+            Source::Generator(_) => write!(f, "Generator"),
+            Source::Finished => write!(f, "Finished"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Iter {
+    source: Rc<RefCell<Source>>, // shared, so every copy of the iterator continues from the same place
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+/// Iterators are only equal to copies of themselves, which share the same source of values
+impl PartialEq for Iter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.source, &other.source)
+    }
+}
+
+impl Iter {
+    fn new(source: Source) -> Self {
+        Self {
+            source: Rc::new(RefCell::new(source)),
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    fn from(source: Source) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::IteratorValue(Iter::new(source))))
+    }
+
+    /// Returns an iterator over the value, or the value itself if it is already an iterator
+    ///
+    /// Lists give their elements, maps give their keys and strings give their characters
+    pub fn from_value(value: &Rc<RefCell<Value>>) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let source = match *value.borrow() {
+            Value::IteratorValue(_) => return Ok(value.clone()),
+            Value::ListValue(ref list) => Source::Elements(list.elements.clone().into_iter()),
+            Value::MapValue(ref map) => Source::Elements(map.keys().into_iter()),
+            Value::SetValue(ref set) => Source::Elements(set.values().into_iter()),
+            Value::StringValue(ref string) => Source::Chars {
+                value: string.value.clone(),
+                offset: 0,
+            },
+            ref other => {
+                return Err(StandardError::new(
+                    "object is not iterable",
+                    other.span(),
+                    Some(format!("the object is a {}", other.object_type()).as_str()),
+                )
+                .with_kind("TypeError"));
+            }
+        };
+
+        Ok(Iter::from(source))
+    }
+
+    /// Returns an iterator that counts from `start` up to (but not including) `end`
+    pub fn range(start: NumberKind, end: NumberKind, step: NumberKind) -> Rc<RefCell<Value>> {
+        Iter::from(Source::Range {
+            current: start,
+            end,
+            step,
+        })
+    }
+
+    /// Returns an iterator over the lines of an open file, which are only read when they are needed
+    pub fn lines(file: File) -> Rc<RefCell<Value>> {
+        Iter::from(Source::Lines(BufReader::new(file).lines()))
+    }
+
+    /// Returns an iterator that runs the body of a generator function up to each `toss`
+    ///
+    /// The body runs on its own stack with its own interpreter, so it can pause in the middle of
+    /// any loop or call and pick up from the same place when the next value is asked for
+    pub fn generator(
+        function: Function,
+        context: Rc<RefCell<Context>>,
+        interpreter: &Interpreter,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let mut interpreter = interpreter.fork();
+        let stack = match DefaultStack::new(GENERATOR_STACK_SIZE) {
+            Ok(stack) => stack,
+            Err(e) => {
+                return Err(StandardError::new(
+                    format!("couldn't make a stack for the generator: {e}").as_str(),
+                    function.span.clone(),
+                    Some("too many generators are alive at once"),
+                )
+                .with_kind("MemoryError"));
+            }
+        };
+        interpreter.set_stack(stack.base().get(), stack.base().get() - stack.limit().get());
+        let coroutine = Coroutine::with_stack(stack, move |yielder: &Yielder, ()| {
+            interpreter.yielder = Some(yielder as *const Yielder);
+
+            match interpreter
+                .visit(function.body_node, &function.arena, context)
+                .error
+            {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        });
+
+        Ok(Iter::from(Source::Generator(coroutine)))
+    }
+
+    /// Returns the next value of the iterator, or `None` once there are no values left
+    pub fn next(&self) -> Result<Option<Rc<RefCell<Value>>>, StandardError> {
+        let Ok(mut source) = self.source.try_borrow_mut() else {
+            return Err(StandardError::new(
+                "iterator is already running",
+                self.span.clone(),
+                Some("a generator can't loop over itself while it is running"),
+            ));
+        };
+
+        let next = match *source {
+            Source::Elements(ref mut elements) => Ok(elements.next()),
+            Source::Chars {
+                ref value,
+                ref mut offset,
+            } => Ok(value[*offset..].chars().next().map(|char| {
+                *offset += char.len_utf8();
+
+                Str::from(&char.to_string())
+            })),
+            Source::Range {
+                ref mut current,
+                ref end,
+                ref step,
+            } => {
+                let is_in_range = if step.is_negative() {
+                    *current > *end
+                } else {
+                    *current < *end
+                };
+
+                if is_in_range {
+                    let next = match (&*current, step) {
+                        (NumberKind::Integer(current), NumberKind::Integer(step)) => {
+                            NumberKind::Integer(current + step)
+                        }
+                        _ => NumberKind::Float(current.to_f64() + step.to_f64()),
+                    };
+
+                    Ok(Some(Number::from(std::mem::replace(current, next))))
+                } else {
+                    Ok(None)
+                }
+            }
+            Source::Lines(ref mut lines) => match lines.next() {
+                Some(Ok(line)) => Ok(Some(Str::from(&line))),
+                Some(Err(_)) => Err(StandardError::new(
+                    "file contents couldn't be read properly",
+                    self.span.clone(),
+                    Some("add a UTF-8 encoded file to read"),
+                )
+                .with_kind("FileError")),
+                None => Ok(None),
+            },
+            Source::Generator(ref mut coroutine) => match coroutine.resume(()) {
+                CoroutineResult::Yield(value) => Ok(Some(value)),
+                CoroutineResult::Return(Ok(())) => Ok(None),
+                CoroutineResult::Return(Err(error)) => Err(error),
+            },
+            Source::Finished => Ok(None),
+        };
+
+        // finished sources are dropped straight away, which also frees the stack of a generator
+        if !matches!(next, Ok(Some(_))) {
+            *source = Source::Finished;
+        }
+
+        next
+    }
+}
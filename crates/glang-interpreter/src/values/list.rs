@@ -1,9 +1,9 @@
 use crate::{
     context::Context,
-    values::{number::Number, value::Value},
+    values::{null::Null, value::Value},
 };
 use glang_attributes::{Span, StandardError};
-use std::{cell::RefCell, iter::zip, rc::Rc};
+use std::{cell::RefCell, cmp::Ordering, iter::zip, rc::Rc};
 
 #[derive(Debug, Clone)]
 pub struct List {
@@ -15,31 +15,28 @@ pub struct List {
 
 impl PartialEq for List {
     fn eq(&self, other: &Self) -> bool {
-        let mut eq = false;
+        self.elements.len() == other.elements.len()
+            && zip(&self.elements, &other.elements)
+                .all(|(a, b)| Rc::ptr_eq(a, b) || *a.borrow() == *b.borrow())
+    }
+}
 
+/// Lists are ordered by their first different element, and a shorter list comes before a longer
+/// list that starts with the same elements
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         for (a, b) in zip(&self.elements, &other.elements) {
-            if a.borrow().as_string() == b.borrow().as_string() {
-                eq = true;
-            } else {
-                eq = false;
+            if Rc::ptr_eq(a, b) {
+                continue;
             }
-        }
-
-        eq
-    }
-
-    fn ne(&self, other: &Self) -> bool {
-        let mut ne = false;
 
-        for (a, b) in zip(&self.elements, &other.elements) {
-            if a.borrow().as_string() != b.borrow().as_string() {
-                ne = true;
-            } else {
-                ne = false;
+            match a.borrow().partial_cmp(&b.borrow())? {
+                Ordering::Equal => continue,
+                ordering => return Some(ordering),
             }
         }
 
-        ne
+        Some(self.elements.len().cmp(&other.elements.len()))
     }
 }
 
@@ -67,7 +64,8 @@ impl List {
                 "cannot change a constant value",
                 self.span.clone(),
                 None,
-            ));
+            )
+            .with_kind("ConstantError"));
         }
 
         if other.borrow().is_const() {
@@ -75,7 +73,8 @@ impl List {
                 "cannot change a constant value",
                 other.borrow().span(),
                 None,
-            ));
+            )
+            .with_kind("ConstantError"));
         }
 
         if operator == "*" {
@@ -85,108 +84,22 @@ impl List {
         match *other.borrow() {
             Value::ListValue(ref value) => match operator {
                 "+" => Ok(self.append(&mut value.elements.clone())),
-                "==" => {
-                    let is_eq = Number::from((self == value) as u8 as f64);
-                    is_eq.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_eq)
-                }
-                "!=" => {
-                    let is_neq = Number::from((self != value) as u8 as f64);
-                    is_neq.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_neq)
-                }
-                ">" => {
-                    let is_gt =
-                        Number::from((self.elements.len() > value.elements.len()) as u8 as f64);
-                    is_gt.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_gt)
-                }
-                "<" => {
-                    let is_lt =
-                        Number::from((self.elements.len() < value.elements.len()) as u8 as f64);
-                    is_lt.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_lt)
-                }
-                ">=" => {
-                    let is_gte =
-                        Number::from((self.elements.len() >= value.elements.len()) as u8 as f64);
-                    is_gte.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_gte)
-                }
-                "<=" => {
-                    let is_lte =
-                        Number::from((self.elements.len() <= value.elements.len()) as u8 as f64);
-                    is_lte.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_lte)
-                }
-                "and" => {
-                    let is_and = Number::from(
-                        (!self.elements.is_empty() && !value.elements.is_empty()) as u8 as f64,
-                    );
-                    is_and.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_and)
-                }
-                "or" => {
-                    let is_or = Number::from(
-                        (!self.elements.is_empty() || !value.elements.is_empty()) as u8 as f64,
-                    );
-                    is_or.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_or)
-                }
                 _ => Err(self.illegal_operation(Some(other.clone()))),
             },
             Value::NumberValue(ref value) => match operator {
-                "^" => {
-                    if value.value < -1.0 {
-                        return Err(StandardError::new(
-                            "cannot access a negative index",
-                            value.span.clone(),
-                            Some(
-                                "use an index greater than or equal to 0 or use -1 to reverse the list",
-                            ),
-                        ));
-                    }
-
-                    if value.value == -1.0 {
-                        return Ok(self.reverse());
-                    }
-
-                    if (value.value as usize) >= self.elements.len() {
-                        return Err(StandardError::new(
-                            "index is out of bounds",
-                            value.span.clone(),
-                            None,
-                        ));
-                    }
-
-                    Ok(self.retrieve(value.value as usize))
-                }
                 "-" => {
-                    if value.value < 0.0 {
+                    if value.value.is_negative() {
                         return Err(StandardError::new(
                             "cannot access a negative index",
                             value.span.clone(),
                             Some("use an index greater than or equal to 0"),
-                        ));
+                        )
+                        .with_kind("IndexError"));
                     }
 
-                    if (value.value as usize) >= self.elements.len() {
-                        return Err(StandardError::new(
-                            "index is out of bounds",
-                            value.span.clone(),
-                            None,
-                        ));
-                    }
+                    let index = value.as_index(self.elements.len())?;
 
-                    Ok(self.remove(value.value as usize))
+                    Ok(self.remove(index))
                 }
                 _ => Err(self.illegal_operation(Some(other.clone()))),
             },
@@ -212,18 +125,70 @@ impl List {
             Span::new(&self.span.filename, self.span.start.clone(), pos_end),
             help_msg.as_deref(),
         )
+        .with_kind("TypeError")
+    }
+
+    pub fn get_index(
+        &self,
+        index: &Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        match *index.borrow() {
+            Value::NumberValue(ref number) => {
+                Ok(self.retrieve(number.as_index(self.elements.len())?))
+            }
+            ref other => Err(StandardError::new(
+                "expected type number",
+                other.span(),
+                Some("lists are indexed with numbers like '0' or '-1'"),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    pub fn set_index(
+        &mut self,
+        index: &Rc<RefCell<Value>>,
+        value: Rc<RefCell<Value>>,
+    ) -> Result<(), StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        match *index.borrow() {
+            Value::NumberValue(ref number) => {
+                let position = number.as_index(self.elements.len())?;
+                self.elements[position] = value;
+
+                Ok(())
+            }
+            ref other => Err(StandardError::new(
+                "expected type number",
+                other.span(),
+                Some("lists are indexed with numbers like '0' or '-1'"),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    pub fn slice(&self, positions: &[usize]) -> Rc<RefCell<Value>> {
+        List::from(positions.iter().map(|i| self.retrieve(*i)).collect())
     }
 
     pub fn push(&mut self, item: Rc<RefCell<Value>>) -> Rc<RefCell<Value>> {
         self.elements.push(item);
 
-        Number::null_value()
+        Null::null_value()
     }
 
     pub fn append(&mut self, other: &mut Vec<Rc<RefCell<Value>>>) -> Rc<RefCell<Value>> {
         self.elements.append(other);
 
-        Number::null_value()
+        Null::null_value()
     }
 
     pub fn remove(&mut self, index: usize) -> Rc<RefCell<Value>> {
@@ -237,6 +202,6 @@ impl List {
     pub fn reverse(&mut self) -> Rc<RefCell<Value>> {
         self.elements.reverse();
 
-        Number::null_value()
+        Null::null_value()
     }
 }
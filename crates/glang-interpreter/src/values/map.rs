@@ -0,0 +1,207 @@
+use crate::{
+    context::Context,
+    values::{null::Null, value::Value},
+};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, rc::Rc};
+
+/// A key and the value stored under it
+type Entry = (Rc<RefCell<Value>>, Rc<RefCell<Value>>);
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub entries: Vec<Entry>, // kept in insertion order
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+impl PartialEq for Map {
+    fn eq(&self, other: &Self) -> bool {
+        if self.entries.len() != other.entries.len() {
+            return false;
+        }
+
+        self.entries
+            .iter()
+            .all(|(key, value)| match other.get(&key.borrow()) {
+                Some(other_value) => {
+                    Rc::ptr_eq(value, &other_value) || *value.borrow() == *other_value.borrow()
+                }
+                None => false,
+            })
+    }
+}
+
+impl Map {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self {
+            entries,
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(entries: Vec<Entry>) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::MapValue(Map::new(entries))))
+    }
+
+    /// Returns an error if the value can't be used as a map key
+    ///
+    /// Keys must be hashable like set elements, so that equal keys always find the same entry
+    pub fn check_key(key: &Rc<RefCell<Value>>) -> Result<(), StandardError> {
+        let key = key.borrow();
+
+        match key.hash_key() {
+            Some(_) => Ok(()),
+            None => Err(StandardError::new(
+                "map keys must be null, booleans, numbers, strings or lists of them",
+                key.span(),
+                Some(format!("the key is a {}", key.object_type()).as_str()),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    pub fn perform_operation(
+        &mut self,
+        operator: &str,
+        other: Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        if let Value::MapValue(ref value) = *other.borrow() {
+            return match operator {
+                "+" => Ok(self.merge(value)),
+                _ => Err(self.illegal_operation(Some(other.clone()))),
+            };
+        }
+
+        match operator {
+            "-" => self.remove_index(&other),
+            _ => Err(self.illegal_operation(Some(other.clone()))),
+        }
+    }
+
+    pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
+        let (pos_end, help_msg) = if let Some(illegal) = other {
+            (
+                illegal.borrow().position_end().clone(),
+                Some(format!(
+                    "the left type is a map and the right type is a {}",
+                    illegal.borrow().object_type()
+                )),
+            )
+        } else {
+            (self.span.end.clone(), None)
+        };
+
+        StandardError::new(
+            "operation not supported by type",
+            Span::new(&self.span.filename, self.span.start.clone(), pos_end),
+            help_msg.as_deref(),
+        )
+        .with_kind("TypeError")
+    }
+
+    pub fn get_index(&self, key: &Rc<RefCell<Value>>) -> Result<Rc<RefCell<Value>>, StandardError> {
+        Map::check_key(key)?;
+
+        match self.get(&key.borrow()) {
+            Some(value) => Ok(value),
+            None => Err(StandardError::new(
+                "key doesn't exist in map",
+                key.borrow().span(),
+                Some(format!("the missing key is '{}'", key.borrow().as_string()).as_str()),
+            )
+            .with_kind("KeyError")),
+        }
+    }
+
+    pub fn set_index(
+        &mut self,
+        key: &Rc<RefCell<Value>>,
+        value: Rc<RefCell<Value>>,
+    ) -> Result<(), StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        Map::check_key(key)?;
+        self.insert(key.clone(), value);
+
+        Ok(())
+    }
+
+    /// Removes the entry with the given key and returns its value
+    pub fn remove_index(
+        &mut self,
+        key: &Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        Map::check_key(key)?;
+
+        match self.remove(&key.borrow()) {
+            Some(value) => Ok(value),
+            None => Err(StandardError::new(
+                "key doesn't exist in map",
+                key.borrow().span(),
+                Some(format!("the missing key is '{}'", key.borrow().as_string()).as_str()),
+            )
+            .with_kind("KeyError")),
+        }
+    }
+
+    pub fn get(&self, key: &Value) -> Option<Rc<RefCell<Value>>> {
+        self.position(key).map(|i| self.entries[i].1.clone())
+    }
+
+    pub fn insert(&mut self, key: Rc<RefCell<Value>>, value: Rc<RefCell<Value>>) {
+        match self.position(&key.borrow()) {
+            Some(i) => self.entries[i].1 = value,
+            // keys are copied so that changing the original object can't change the key
+            None => self.entries.push((key.borrow().frozen_copy(), value)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &Value) -> Option<Rc<RefCell<Value>>> {
+        self.position(key).map(|i| self.entries.remove(i).1)
+    }
+
+    pub fn merge(&mut self, other: &Map) -> Rc<RefCell<Value>> {
+        for (key, value) in other.entries.iter() {
+            self.insert(key.clone(), value.clone());
+        }
+
+        Null::null_value()
+    }
+
+    pub fn keys(&self) -> Vec<Rc<RefCell<Value>>> {
+        self.entries.iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    fn position(&self, key: &Value) -> Option<usize> {
+        self.entries.iter().position(|(k, _)| *k.borrow() == *key)
+    }
+}
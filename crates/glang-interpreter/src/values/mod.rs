@@ -1,13 +1,29 @@
+mod boolean;
+mod breed;
+mod error;
 mod function;
+mod iterator;
 mod list;
+mod map;
+mod module;
+mod null;
 mod number;
+mod set;
 mod string;
 mod value;
 
 pub use {
-    function::{BuiltInFunction, Function},
+    boolean::Boolean,
+    breed::{Breed, Instance},
+    error::Error,
+    function::{ArgumentDefault, BuiltInFunction, Function, Parameters},
+    iterator::{Iter, Yielder},
     list::List,
+    map::Map,
+    module::Module,
+    null::Null,
     number::Number,
+    set::Set,
     string::Str,
     value::Value,
 };
@@ -0,0 +1,42 @@
+use crate::{context::Context, symbol_table::SymbolTable, values::value::Value};
+use glang_attributes::Span;
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub name: String,
+    pub symbol_table: Rc<RefCell<SymbolTable>>, // the top level of the fetched file
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+/// Modules are equal when they were made from the same fetched file
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.symbol_table, &other.symbol_table)
+    }
+}
+
+impl Module {
+    pub fn new(name: String, symbol_table: Rc<RefCell<SymbolTable>>) -> Self {
+        Self {
+            name,
+            symbol_table,
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn from(name: String, symbol_table: Rc<RefCell<SymbolTable>>) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::ModuleValue(Module::new(
+            name,
+            symbol_table,
+        ))))
+    }
+
+    pub fn get_member(&self, name: &str) -> Option<Rc<RefCell<Value>>> {
+        self.symbol_table.borrow().symbols.get(name).cloned()
+    }
+}
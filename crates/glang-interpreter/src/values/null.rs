@@ -0,0 +1,60 @@
+use crate::{context::Context, values::value::Value};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct Null {
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+impl Default for Null {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Null {
+    pub fn new() -> Self {
+        Self {
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    pub fn null_value() -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::NullValue(Null::new())))
+    }
+
+    pub fn perform_operation(
+        &self,
+        _operator: &str,
+        other: Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        // comparisons are handled by `Value`, and null don't support any other operator
+        Err(self.illegal_operation(Some(other)))
+    }
+
+    pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
+        let (pos_end, help_msg) = if let Some(illegal) = other {
+            (
+                illegal.borrow().position_end().clone(),
+                Some(format!(
+                    "the left type is null and the right type is a {}",
+                    illegal.borrow().object_type()
+                )),
+            )
+        } else {
+            (self.span.end.clone(), None)
+        };
+
+        StandardError::new(
+            "operation not supported by type",
+            Span::new(&self.span.filename, self.span.start.clone(), pos_end),
+            help_msg.as_deref(),
+        )
+        .with_kind("TypeError")
+    }
+}
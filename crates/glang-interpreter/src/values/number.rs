@@ -1,39 +1,192 @@
 use crate::{context::Context, values::value::Value};
 use glang_attributes::{Span, StandardError};
-use std::{cell::RefCell, rc::Rc};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
+use std::{cell::RefCell, cmp::Ordering, fmt, rc::Rc};
+
+/// The most bits an integer raised to a power can have, about 300,000 digits
+const MAX_POWER_BITS: u64 = 1 << 20;
+
+/// The two ways a number can be stored
+///
+/// Integers are exact and grow as large as they need to, floats are only used when a value
+/// has a fractional part or when an integer is mixed with a float
+#[derive(Debug, Clone)]
+pub enum NumberKind {
+    Integer(BigInt),
+    Float(f64),
+}
+
+impl NumberKind {
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            NumberKind::Integer(value) => value.to_f64().unwrap_or(f64::NAN),
+            NumberKind::Float(value) => *value,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            NumberKind::Integer(value) => value.is_zero(),
+            NumberKind::Float(value) => *value == 0.0,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        match self {
+            NumberKind::Integer(value) => value.is_negative(),
+            NumberKind::Float(value) => *value < 0.0,
+        }
+    }
+}
+
+impl PartialEq for NumberKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Integers are compared with floats exactly, so a large integer is never equal to a float it
+/// only rounds to
+impl PartialOrd for NumberKind {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (NumberKind::Integer(left), NumberKind::Integer(right)) => Some(left.cmp(right)),
+            (NumberKind::Float(left), NumberKind::Float(right)) => left.partial_cmp(right),
+            (NumberKind::Integer(left), NumberKind::Float(right)) => {
+                compare_integer_to_float(left, *right)
+            }
+            (NumberKind::Float(left), NumberKind::Integer(right)) => {
+                compare_integer_to_float(right, *left).map(Ordering::reverse)
+            }
+        }
+    }
+}
+
+fn compare_integer_to_float(integer: &BigInt, float: f64) -> Option<Ordering> {
+    if float.is_nan() {
+        return None;
+    }
+
+    if float.is_infinite() {
+        return Some(if float > 0.0 {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        });
+    }
+
+    // the whole part of a finite float is always an exact integer
+    let whole = BigInt::from_f64(float.floor())?;
+
+    match integer.cmp(&whole) {
+        Ordering::Equal if float.fract() != 0.0 => Some(Ordering::Less),
+        ordering => Some(ordering),
+    }
+}
+
+impl fmt::Display for NumberKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberKind::Integer(value) => write!(f, "{value}"),
+            // the debug format always keeps the decimal point, so `3.0` doesn't print as `3`
+            NumberKind::Float(value) => write!(f, "{value:?}"),
+        }
+    }
+}
+
+impl From<BigInt> for NumberKind {
+    fn from(value: BigInt) -> Self {
+        NumberKind::Integer(value)
+    }
+}
+
+impl From<i32> for NumberKind {
+    fn from(value: i32) -> Self {
+        NumberKind::Integer(BigInt::from(value))
+    }
+}
+
+impl From<usize> for NumberKind {
+    fn from(value: usize) -> Self {
+        NumberKind::Integer(BigInt::from(value))
+    }
+}
+
+impl From<f64> for NumberKind {
+    fn from(value: f64) -> Self {
+        NumberKind::Float(value)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Number {
-    pub value: f64,
+    pub value: NumberKind,
     pub context: Option<Rc<RefCell<Context>>>,
     pub is_const: bool,
     pub span: Span,
 }
 
 impl Number {
-    pub fn new(value: f64) -> Self {
+    pub fn new(value: impl Into<NumberKind>) -> Self {
         Self {
-            value,
+            value: value.into(),
             context: None,
             is_const: false,
             span: Span::empty(),
         }
     }
 
-    pub fn from(value: f64) -> Rc<RefCell<Value>> {
+    pub fn from(value: impl Into<NumberKind>) -> Rc<RefCell<Value>> {
         Rc::new(RefCell::new(Value::NumberValue(Number::new(value))))
     }
 
-    pub fn null_value() -> Rc<RefCell<Value>> {
-        Rc::new(RefCell::new(Value::NumberValue(Number::new(0.0))))
+    /// Parses a number from text, like `"3"` or `"3.0"`
+    ///
+    /// Whole numbers without a decimal point become integers, anything else becomes a float
+    pub fn parse(text: &str) -> Option<NumberKind> {
+        text.parse::<BigInt>()
+            .map(NumberKind::Integer)
+            .or_else(|_| text.parse::<f64>().map(NumberKind::Float))
+            .ok()
     }
 
-    pub fn true_value() -> Rc<RefCell<Value>> {
-        Rc::new(RefCell::new(Value::NumberValue(Number::new(1.0))))
-    }
+    /// Converts the number into a position inside of a collection with the given length
+    ///
+    /// Negative numbers count backwards from the end, so `-1` is the last position
+    pub fn as_index(&self, length: usize) -> Result<usize, StandardError> {
+        let NumberKind::Integer(ref value) = self.value else {
+            return Err(StandardError::new(
+                "index must be a whole number",
+                self.span.clone(),
+                Some("the index is a float, use an integer like '3' instead of '3.0'"),
+            )
+            .with_kind("ValueError"));
+        };
 
-    pub fn false_value() -> Rc<RefCell<Value>> {
-        Rc::new(RefCell::new(Value::NumberValue(Number::new(0.0))))
+        let index = if value.is_negative() {
+            value + BigInt::from(length)
+        } else {
+            value.clone()
+        };
+
+        match index.to_usize() {
+            Some(index) if index < length => Ok(index),
+            _ => Err(StandardError::new(
+                "index is out of bounds",
+                self.span.clone(),
+                Some(
+                    format!(
+                        "use an index from {} to {}",
+                        -(length as i64),
+                        length as i64 - 1
+                    )
+                    .as_str(),
+                ),
+            )
+            .with_kind("IndexError")),
+        }
     }
 
     pub fn perform_operation(
@@ -43,68 +196,136 @@ impl Number {
     ) -> Result<Rc<RefCell<Value>>, StandardError> {
         match *other.borrow() {
             Value::NumberValue(ref value) => {
-                let left_val = self.value;
-                let right_val = value.value;
+                let left_val = &self.value;
+                let right_val = &value.value;
 
                 let result = match operator {
-                    "+" => Some(left_val + right_val),
-                    "-" => Some(left_val - right_val),
-                    "*" => Some(left_val * right_val),
-                    "/" => {
-                        if right_val == 0.0 {
+                    "+" => Number::from(Number::arithmetic(
+                        left_val,
+                        right_val,
+                        |a, b| a + b,
+                        |a, b| a + b,
+                    )),
+                    "-" => Number::from(Number::arithmetic(
+                        left_val,
+                        right_val,
+                        |a, b| a - b,
+                        |a, b| a - b,
+                    )),
+                    "*" => Number::from(Number::arithmetic(
+                        left_val,
+                        right_val,
+                        |a, b| a * b,
+                        |a, b| a * b,
+                    )),
+                    "/" | "//" => {
+                        if right_val.is_zero() {
                             return Err(StandardError::new(
                                 "division by zero",
                                 value.span.clone(),
                                 None,
-                            ));
+                            )
+                            .with_kind("ZeroDivisionError"));
                         }
-                        Some(left_val / right_val)
+
+                        Number::from(Number::divide(left_val, right_val, operator == "//"))
                     }
                     "^" => {
-                        if right_val < 0.0 {
+                        if right_val.is_negative() {
                             return Err(StandardError::new(
                                 "powered by operator less than or equal to 0",
                                 value.span.clone(),
                                 None,
-                            ));
+                            )
+                            .with_kind("ValueError"));
                         }
 
-                        Some(left_val.powf(right_val))
+                        match (left_val, right_val) {
+                            (NumberKind::Integer(a), NumberKind::Integer(b)) => match b.to_u32() {
+                                // the result has at least this many bits, so huge results are
+                                // refused before spending any time on them
+                                Some(exponent)
+                                    if a.bits().saturating_sub(1) * u64::from(exponent)
+                                        > MAX_POWER_BITS =>
+                                {
+                                    return Err(StandardError::new(
+                                        "result of the power is too large",
+                                        value.span.clone(),
+                                        Some(
+                                            "use a float base like '10.0' for an approximate result",
+                                        ),
+                                    )
+                                    .with_kind("ValueError"));
+                                }
+                                Some(exponent) => Number::from(a.pow(exponent)),
+                                None => {
+                                    return Err(StandardError::new(
+                                        "exponent is too large",
+                                        value.span.clone(),
+                                        Some(
+                                            "use a float exponent like '2.0' for an approximate result",
+                                        ),
+                                    ).with_kind("ValueError"));
+                                }
+                            },
+                            _ => Number::from(left_val.to_f64().powf(right_val.to_f64())),
+                        }
                     }
                     "%" => {
-                        if right_val <= 0.0 {
+                        if right_val.is_negative() || right_val.is_zero() {
                             return Err(StandardError::new(
                                 "modded by operator less than or equal to 0",
                                 value.span.clone(),
                                 None,
-                            ));
+                            )
+                            .with_kind("ValueError"));
                         }
 
-                        Some(left_val.rem_euclid(right_val))
+                        Number::from(Number::arithmetic(
+                            left_val,
+                            right_val,
+                            |a, b| a.mod_floor(b),
+                            |a, b| a.rem_euclid(b),
+                        ))
                     }
-                    "==" => Some((left_val == right_val) as u8 as f64),
-                    "!=" => Some((left_val != right_val) as u8 as f64),
-                    "<" => Some((left_val < right_val) as u8 as f64),
-                    ">" => Some((left_val > right_val) as u8 as f64),
-                    "<=" => Some((left_val <= right_val) as u8 as f64),
-                    ">=" => Some((left_val >= right_val) as u8 as f64),
-                    "and" => Some(((left_val != 0.0) && (right_val != 0.0)) as u8 as f64),
-                    "or" => Some(((left_val != 0.0) || (right_val != 0.0)) as u8 as f64),
-                    "not" => Some(if self.value == 0.0 { 1.0 } else { 0.0 }),
                     _ => return Err(self.illegal_operation(Some(other.clone()))),
                 };
+                result.borrow_mut().set_context(self.context.clone());
 
-                let comparison_result = Number::from(result.unwrap());
-                comparison_result
-                    .borrow_mut()
-                    .set_context(self.context.clone());
-
-                Ok(comparison_result)
+                Ok(result)
             }
             _ => Err(self.illegal_operation(Some(other.clone()))),
         }
     }
 
+    /// Applies an operation exactly if both numbers are integers, otherwise as floats
+    fn arithmetic(
+        left: &NumberKind,
+        right: &NumberKind,
+        integer_op: fn(&BigInt, &BigInt) -> BigInt,
+        float_op: fn(f64, f64) -> f64,
+    ) -> NumberKind {
+        match (left, right) {
+            (NumberKind::Integer(a), NumberKind::Integer(b)) => {
+                NumberKind::Integer(integer_op(a, b))
+            }
+            _ => NumberKind::Float(float_op(left.to_f64(), right.to_f64())),
+        }
+    }
+
+    /// Divides two numbers, where `/` always gives a float and floor division keeps integers
+    ///
+    /// Floor division always rounds down, so `7 // 2` is `3` and `-7 // 2` is `-4`
+    fn divide(left: &NumberKind, right: &NumberKind, floor: bool) -> NumberKind {
+        match (left, right) {
+            (NumberKind::Integer(a), NumberKind::Integer(b)) if floor => {
+                NumberKind::Integer(a.div_floor(b))
+            }
+            _ if floor => NumberKind::Float((left.to_f64() / right.to_f64()).floor()),
+            _ => NumberKind::Float(left.to_f64() / right.to_f64()),
+        }
+    }
+
     pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
         let (pos_end, help_msg) = if let Some(illegal) = other {
             (
@@ -123,5 +344,6 @@ impl Number {
             Span::new(&self.span.filename, self.span.start.clone(), pos_end),
             help_msg.as_deref(),
         )
+        .with_kind("TypeError")
     }
 }
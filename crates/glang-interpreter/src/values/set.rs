@@ -0,0 +1,169 @@
+use crate::{
+    context::Context,
+    values::{
+        list::List,
+        value::{HashKey, Value},
+    },
+};
+use glang_attributes::{Span, StandardError};
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+#[derive(Debug, Clone)]
+pub struct Set {
+    pub elements: Vec<(HashKey, Rc<RefCell<Value>>)>, // kept in insertion order
+    keys: HashSet<HashKey>,
+    pub context: Option<Rc<RefCell<Context>>>,
+    pub is_const: bool,
+    pub span: Span,
+}
+
+impl PartialEq for Set {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys
+    }
+}
+
+impl Default for Set {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Set {
+    pub fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+            keys: HashSet::new(),
+            context: None,
+            is_const: false,
+            span: Span::empty(),
+        }
+    }
+
+    /// Returns a set of the values, where repeated values are only kept the first time
+    pub fn from(values: &[Rc<RefCell<Value>>]) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let mut set = Set::new();
+
+        for value in values {
+            set.insert(value)?;
+        }
+
+        Ok(Set::wrap(set))
+    }
+
+    fn wrap(set: Set) -> Rc<RefCell<Value>> {
+        Rc::new(RefCell::new(Value::SetValue(set)))
+    }
+
+    /// Returns the hash key of the value, or an error if the value can't be put in a set
+    pub fn key_of(value: &Rc<RefCell<Value>>) -> Result<HashKey, StandardError> {
+        let value = value.borrow();
+
+        value.hash_key().ok_or_else(|| {
+            StandardError::new(
+                "set elements must be null, booleans, numbers, strings or lists of them",
+                value.span(),
+                Some(format!("the element is a {}", value.object_type()).as_str()),
+            )
+            .with_kind("TypeError")
+        })
+    }
+
+    pub fn contains(&self, key: &HashKey) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Adds the value if it isn't in the set yet, returning whether it was added
+    pub fn insert(&mut self, value: &Rc<RefCell<Value>>) -> Result<bool, StandardError> {
+        self.check_const()?;
+
+        let key = Set::key_of(value)?;
+
+        if !self.keys.insert(key.clone()) {
+            return Ok(false);
+        }
+
+        self.elements.push((key, value.borrow().frozen_copy()));
+
+        Ok(true)
+    }
+
+    /// Removes the value from the set, returning whether it was there
+    pub fn remove(&mut self, value: &Rc<RefCell<Value>>) -> Result<bool, StandardError> {
+        self.check_const()?;
+
+        let key = Set::key_of(value)?;
+
+        if !self.keys.remove(&key) {
+            return Ok(false);
+        }
+
+        self.elements.retain(|(element_key, _)| *element_key != key);
+
+        Ok(true)
+    }
+
+    /// Returns copies of the elements, so that changing one can't change what the set holds
+    pub fn values(&self) -> Vec<Rc<RefCell<Value>>> {
+        self.elements
+            .iter()
+            .map(|(_, element)| element.borrow().frozen_copy())
+            .collect()
+    }
+
+    pub fn union(&self, other: &Set) -> Rc<RefCell<Value>> {
+        let mut union = self.without_const();
+
+        for (key, element) in other.elements.iter() {
+            if union.keys.insert(key.clone()) {
+                union.elements.push((key.clone(), element.clone()));
+            }
+        }
+
+        Set::wrap(union)
+    }
+
+    pub fn intersection(&self, other: &Set) -> Rc<RefCell<Value>> {
+        self.filter(|key| other.contains(key))
+    }
+
+    pub fn difference(&self, other: &Set) -> Rc<RefCell<Value>> {
+        self.filter(|key| !other.contains(key))
+    }
+
+    pub fn to_list(&self) -> Rc<RefCell<Value>> {
+        List::from(self.values())
+    }
+
+    fn filter(&self, keep: impl Fn(&HashKey) -> bool) -> Rc<RefCell<Value>> {
+        let mut filtered = Set::new();
+
+        for (key, element) in self.elements.iter().filter(|(key, _)| keep(key)) {
+            filtered.keys.insert(key.clone());
+            filtered.elements.push((key.clone(), element.clone()));
+        }
+
+        Set::wrap(filtered)
+    }
+
+    fn without_const(&self) -> Set {
+        let mut set = self.clone();
+        set.is_const = false;
+        set.context = None;
+
+        set
+    }
+
+    fn check_const(&self) -> Result<(), StandardError> {
+        if self.is_const {
+            return Err(StandardError::new(
+                "cannot change a constant value",
+                self.span.clone(),
+                None,
+            )
+            .with_kind("ConstantError"));
+        }
+
+        Ok(())
+    }
+}
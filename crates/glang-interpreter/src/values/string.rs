@@ -1,8 +1,9 @@
 use crate::{
     context::Context,
-    values::{number::Number, value::Value},
+    values::{number::NumberKind, value::Value},
 };
 use glang_attributes::{Span, StandardError};
+use num_traits::ToPrimitive;
 use std::{cell::RefCell, rc::Rc};
 
 #[derive(Debug, Clone)]
@@ -49,108 +50,32 @@ impl Str {
 
                     Ok(Rc::new(RefCell::new(Value::StringValue(copy))))
                 }
-                "==" => {
-                    let is_neq = Number::from((self.value == value.value) as u8 as f64);
-                    is_neq.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_neq)
-                }
-                "!=" => {
-                    let is_neq = Number::from((self.value != value.value) as u8 as f64);
-                    is_neq.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_neq)
-                }
-                ">" => {
-                    let is_gt = Number::from((self.value > value.value) as u8 as f64);
-                    is_gt.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_gt)
-                }
-                "<" => {
-                    let is_lt = Number::from((self.value < value.value) as u8 as f64);
-                    is_lt.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_lt)
-                }
-                ">=" => {
-                    let is_gte = Number::from((self.value >= value.value) as u8 as f64);
-                    is_gte.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_gte)
-                }
-                "<=" => {
-                    let is_lte = Number::from((self.value <= value.value) as u8 as f64);
-                    is_lte.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_lte)
-                }
-                "and" => {
-                    let is_and = Number::from(
-                        (!self.value.is_empty() && !value.value.is_empty()) as u8 as f64,
-                    );
-                    is_and.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_and)
-                }
-                "or" => {
-                    let is_or = Number::from(
-                        (!self.value.is_empty() || !value.value.is_empty()) as u8 as f64,
-                    );
-                    is_or.borrow_mut().set_context(self.context.clone());
-
-                    Ok(is_or)
-                }
                 _ => Err(self.illegal_operation(Some(other.clone()))),
             },
             Value::NumberValue(ref value) => match operator {
                 "*" => {
-                    if value.value < 0.0 {
+                    if value.value.is_negative() {
                         return Err(StandardError::new(
                             "cannot multiply string by a negative value",
                             other.borrow().span(),
                             None,
-                        ));
+                        )
+                        .with_kind("ValueError"));
                     }
 
-                    let mut copy = self.clone();
-                    copy.value = self.value.repeat(value.value as usize);
-
-                    Ok(Rc::new(RefCell::new(Value::StringValue(copy))))
-                }
-                "^" => {
-                    if value.value < -1.0 {
+                    let NumberKind::Integer(ref times) = value.value else {
                         return Err(StandardError::new(
-                            "cannot access a negative index",
-                            value.span.clone(),
-                            Some(
-                                "use an index greater than or equal to 0 or use -1 to reverse the string",
-                            ),
-                        ));
-                    }
-
-                    if value.value == -1.0 {
-                        return Ok(Str::from(
-                            self.value.chars().rev().collect::<String>().as_str(),
-                        ));
-                    }
+                            "cannot multiply string by a float",
+                            other.borrow().span(),
+                            Some("use a whole number like '3' instead of '3.0'"),
+                        )
+                        .with_kind("ValueError"));
+                    };
 
-                    if (value.value as usize) >= self.value.len() {
-                        return Err(StandardError::new(
-                            "index is out of bounds",
-                            value.span.clone(),
-                            None,
-                        ));
-                    }
+                    let mut copy = self.clone();
+                    copy.value = self.value.repeat(times.to_usize().unwrap_or(usize::MAX));
 
-                    Ok(Str::from(
-                        self.value
-                            .chars()
-                            .nth(value.value as usize)
-                            .unwrap()
-                            .to_string()
-                            .as_str(),
-                    ))
+                    Ok(Rc::new(RefCell::new(Value::StringValue(copy))))
                 }
                 _ => Err(self.illegal_operation(Some(other.clone()))),
             },
@@ -158,6 +83,33 @@ impl Str {
         }
     }
 
+    pub fn get_index(
+        &self,
+        index: &Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        match *index.borrow() {
+            Value::NumberValue(ref number) => {
+                let position = number.as_index(self.value.chars().count())?;
+
+                Ok(Str::from(
+                    &self.value.chars().nth(position).unwrap().to_string(),
+                ))
+            }
+            ref other => Err(StandardError::new(
+                "expected type number",
+                other.span(),
+                Some("strings are indexed with numbers like '0' or '-1'"),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    pub fn slice(&self, positions: &[usize]) -> Rc<RefCell<Value>> {
+        let chars: Vec<char> = self.value.chars().collect();
+
+        Str::from(&positions.iter().map(|i| chars[*i]).collect::<String>())
+    }
+
     pub fn illegal_operation(&self, other: Option<Rc<RefCell<Value>>>) -> StandardError {
         let (pos_end, help_msg) = if let Some(illegal) = other {
             (
@@ -176,5 +128,6 @@ impl Str {
             Span::new(&self.span.filename, self.span.start.clone(), pos_end),
             help_msg.as_deref(),
         )
+        .with_kind("TypeError")
     }
 }
@@ -1,82 +1,213 @@
 use crate::{
     context::Context,
     values::{
+        boolean::Boolean,
+        breed::{Breed, Instance},
+        error::Error,
         function::{BuiltInFunction, Function},
+        iterator::Iter,
         list::List,
-        number::Number,
+        map::Map,
+        module::Module,
+        null::Null,
+        number::{Number, NumberKind},
+        set::Set,
         string::Str,
     },
 };
-use glang_attributes::{Position, Span, StandardError};
-use std::{cell::RefCell, rc::Rc};
+use glang_attributes::{
+    ITERATOR_METHODS, LIST_METHODS, MAP_METHODS, Position, SET_METHODS, STRING_METHODS, Span,
+    StandardError,
+};
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, Signed, ToPrimitive};
+use std::{cell::RefCell, cmp::Ordering, rc::Rc};
 
 #[derive(Debug, Clone)]
 pub enum Value {
+    NullValue(Null),
+    BooleanValue(Boolean),
     NumberValue(Number),
     ListValue(List),
+    MapValue(Map),
+    SetValue(Set),
     StringValue(Str),
     FunctionValue(Function),
     BuiltInFunction(BuiltInFunction),
+    IteratorValue(Iter),
+    ErrorValue(Error),
+    BreedValue(Breed),
+    InstanceValue(Instance),
+    ModuleValue(Module),
+}
+
+/// The hashable form of a value, used to find elements of a set
+///
+/// Values that are equal with `==` always have the same key, so whole floats use the integer key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HashKey {
+    Null,
+    Boolean(bool),
+    Integer(BigInt),
+    Float(u64),
+    String(String),
+    List(Vec<HashKey>),
+}
+
+/// Values are equal when they have the same type and the same contents
+///
+/// Numbers compare by value, so `1 == 1.0`, and collections compare their elements with `==`.
+/// Functions, iterators, breeds and modules are only equal to themselves
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::NullValue(_), Value::NullValue(_)) => true,
+            (Value::BooleanValue(a), Value::BooleanValue(b)) => a.value == b.value,
+            (Value::NumberValue(a), Value::NumberValue(b)) => a.value == b.value,
+            (Value::StringValue(a), Value::StringValue(b)) => a.value == b.value,
+            (Value::ListValue(a), Value::ListValue(b)) => a == b,
+            (Value::MapValue(a), Value::MapValue(b)) => a == b,
+            (Value::SetValue(a), Value::SetValue(b)) => a == b,
+            (Value::InstanceValue(a), Value::InstanceValue(b)) => a == b,
+            (Value::FunctionValue(a), Value::FunctionValue(b)) => a == b,
+            (Value::BuiltInFunction(a), Value::BuiltInFunction(b)) => a == b,
+            (Value::IteratorValue(a), Value::IteratorValue(b)) => a == b,
+            (Value::ErrorValue(a), Value::ErrorValue(b)) => a == b,
+            (Value::BreedValue(a), Value::BreedValue(b)) => a == b,
+            (Value::ModuleValue(a), Value::ModuleValue(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Numbers, strings and booleans have a total order, and lists are ordered element by element
+///
+/// Other values can only be ordered against a value they are equal to
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Value::NumberValue(a), Value::NumberValue(b)) => a.value.partial_cmp(&b.value),
+            (Value::StringValue(a), Value::StringValue(b)) => Some(a.value.cmp(&b.value)),
+            (Value::BooleanValue(a), Value::BooleanValue(b)) => Some(a.value.cmp(&b.value)),
+            (Value::ListValue(a), Value::ListValue(b)) => a.partial_cmp(b),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
 }
 
 impl Value {
     pub fn span(&self) -> Span {
         match self {
+            Value::NullValue(value) => value.span.clone(),
+            Value::BooleanValue(value) => value.span.clone(),
             Value::NumberValue(value) => value.span.clone(),
             Value::ListValue(value) => value.span.clone(),
+            Value::MapValue(value) => value.span.clone(),
             Value::StringValue(value) => value.span.clone(),
             Value::FunctionValue(value) => value.span.clone(),
             Value::BuiltInFunction(value) => value.span.clone(),
+            Value::SetValue(value) => value.span.clone(),
+            Value::ErrorValue(value) => value.span.clone(),
+            Value::IteratorValue(value) => value.span.clone(),
+            Value::ModuleValue(value) => value.span.clone(),
+            Value::BreedValue(value) => value.span.clone(),
+            Value::InstanceValue(value) => value.span.clone(),
         }
     }
 
     pub fn position_start(&self) -> Position {
         match self {
+            Value::NullValue(value) => value.span.start.clone(),
+            Value::BooleanValue(value) => value.span.start.clone(),
             Value::NumberValue(value) => value.span.start.clone(),
             Value::ListValue(value) => value.span.start.clone(),
+            Value::MapValue(value) => value.span.start.clone(),
             Value::StringValue(value) => value.span.start.clone(),
             Value::FunctionValue(value) => value.span.start.clone(),
             Value::BuiltInFunction(value) => value.span.start.clone(),
+            Value::SetValue(value) => value.span.start.clone(),
+            Value::ErrorValue(value) => value.span.start.clone(),
+            Value::IteratorValue(value) => value.span.start.clone(),
+            Value::ModuleValue(value) => value.span.start.clone(),
+            Value::BreedValue(value) => value.span.start.clone(),
+            Value::InstanceValue(value) => value.span.start.clone(),
         }
     }
 
     pub fn position_end(&self) -> Position {
         match self {
+            Value::NullValue(value) => value.span.end.clone(),
+            Value::BooleanValue(value) => value.span.end.clone(),
             Value::NumberValue(value) => value.span.end.clone(),
             Value::ListValue(value) => value.span.end.clone(),
+            Value::MapValue(value) => value.span.end.clone(),
             Value::StringValue(value) => value.span.end.clone(),
             Value::FunctionValue(value) => value.span.end.clone(),
             Value::BuiltInFunction(value) => value.span.end.clone(),
+            Value::SetValue(value) => value.span.end.clone(),
+            Value::ErrorValue(value) => value.span.end.clone(),
+            Value::IteratorValue(value) => value.span.end.clone(),
+            Value::ModuleValue(value) => value.span.end.clone(),
+            Value::BreedValue(value) => value.span.end.clone(),
+            Value::InstanceValue(value) => value.span.end.clone(),
         }
     }
 
     pub fn set_span(&mut self, span: Span) {
         match self {
+            Value::NullValue(value) => value.span = span,
+            Value::BooleanValue(value) => value.span = span,
             Value::NumberValue(value) => value.span = span,
             Value::ListValue(value) => value.span = span,
+            Value::MapValue(value) => value.span = span,
             Value::StringValue(value) => value.span = span,
             Value::FunctionValue(value) => value.span = span,
             Value::BuiltInFunction(value) => value.span = span,
+            Value::SetValue(value) => value.span = span,
+            Value::ErrorValue(value) => value.span = span,
+            Value::IteratorValue(value) => value.span = span,
+            Value::ModuleValue(value) => value.span = span,
+            Value::BreedValue(value) => value.span = span,
+            Value::InstanceValue(value) => value.span = span,
         }
     }
 
     pub fn set_context(&mut self, context: Option<Rc<RefCell<Context>>>) {
         match self {
+            Value::NullValue(value) => value.context = context,
+            Value::BooleanValue(value) => value.context = context,
             Value::NumberValue(value) => value.context = context,
             Value::ListValue(value) => value.context = context,
+            Value::MapValue(value) => value.context = context,
             Value::StringValue(value) => value.context = context,
             Value::FunctionValue(value) => value.context = context,
             Value::BuiltInFunction(value) => value.context = context,
+            Value::SetValue(value) => value.context = context,
+            Value::ErrorValue(value) => value.context = context,
+            Value::IteratorValue(value) => value.context = context,
+            Value::ModuleValue(value) => value.context = context,
+            Value::BreedValue(value) => value.context = context,
+            Value::InstanceValue(value) => value.context = context,
         }
     }
 
     pub fn set_const(&mut self, is_const: bool) {
         match self {
+            Value::NullValue(value) => value.is_const = is_const,
+            Value::BooleanValue(value) => value.is_const = is_const,
             Value::NumberValue(value) => value.is_const = is_const,
             Value::ListValue(value) => value.is_const = is_const,
+            Value::MapValue(value) => value.is_const = is_const,
             Value::StringValue(value) => value.is_const = is_const,
             Value::FunctionValue(value) => value.is_const = is_const,
             Value::BuiltInFunction(value) => value.is_const = is_const,
+            Value::SetValue(value) => value.is_const = is_const,
+            Value::ErrorValue(value) => value.is_const = is_const,
+            Value::IteratorValue(value) => value.is_const = is_const,
+            Value::ModuleValue(value) => value.is_const = is_const,
+            Value::BreedValue(value) => value.is_const = is_const,
+            Value::InstanceValue(value) => value.is_const = is_const,
         }
     }
 
@@ -85,50 +216,362 @@ impl Value {
         operator: &str,
         other: Rc<RefCell<Value>>,
     ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        // comparisons work the same for every type, so that they always agree with each other,
+        // while 'and' and 'or' are handled by the interpreter so that they can short-circuit
+        if Value::is_comparison(operator) {
+            return self.compare(operator, &other.borrow());
+        }
+
         match self {
+            Value::NullValue(value) => value.perform_operation(operator, other),
+            Value::BooleanValue(value) => value.perform_operation(operator, other),
             Value::NumberValue(value) => value.perform_operation(operator, other),
             Value::ListValue(value) => value.perform_operation(operator, other),
+            Value::MapValue(value) => value.perform_operation(operator, other),
             Value::StringValue(value) => value.perform_operation(operator, other),
+            Value::InstanceValue(value) => value.perform_operation(operator, other),
             _ => Err(StandardError::new(
                 format!("type doesn't support the '{operator}' operator").as_str(),
                 self.span(),
                 None,
-            )),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    pub fn is_comparison(operator: &str) -> bool {
+        matches!(operator, "==" | "!=" | "<" | ">" | "<=" | ">=")
+    }
+
+    /// Compares the values with a comparison operator, which only has to read both of them
+    pub fn compare(
+        &self,
+        operator: &str,
+        other: &Value,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        match operator {
+            "==" => return Ok(Boolean::from(self == other)),
+            "!=" => return Ok(Boolean::from(self != other)),
+            _ => {}
+        }
+
+        let Some(ordering) = self.partial_cmp(other) else {
+            return Err(StandardError::new(
+                "values can't be ordered",
+                Span::new(
+                    &self.span().filename,
+                    self.position_start(),
+                    other.position_end(),
+                ),
+                Some(
+                    format!(
+                        "the left type is a {} and the right type is a {}",
+                        self.object_type(),
+                        other.object_type()
+                    )
+                    .as_str(),
+                ),
+            )
+            .with_kind("TypeError"));
+        };
+
+        Ok(Boolean::from(match operator {
+            "<" => ordering.is_lt(),
+            ">" => ordering.is_gt(),
+            "<=" => ordering.is_le(),
+            _ => ordering.is_ge(),
+        }))
+    }
+
+    pub fn index(&self, index: Rc<RefCell<Value>>) -> Result<Rc<RefCell<Value>>, StandardError> {
+        match self {
+            Value::ListValue(value) => value.get_index(&index),
+            Value::MapValue(value) => value.get_index(&index),
+            Value::StringValue(value) => value.get_index(&index),
+            _ => Err(StandardError::new(
+                "object is not indexable",
+                self.span(),
+                Some(format!("the object is a {}", self.object_type()).as_str()),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    /// Returns the field, method or module member with the given name, `this` must be the object itself
+    pub fn get_member(
+        &self,
+        name: &str,
+        this: &Rc<RefCell<Value>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let member = match self {
+            Value::InstanceValue(value) => value.get_member(name, this),
+            Value::ModuleValue(value) => value.get_member(name),
+            Value::ErrorValue(value) => value.get_member(name),
+            Value::StringValue(_) if STRING_METHODS.contains(&name) => {
+                Some(BuiltInFunction::method(name, this.clone()))
+            }
+            Value::ListValue(_) if LIST_METHODS.contains(&name) => {
+                Some(BuiltInFunction::method(name, this.clone()))
+            }
+            Value::MapValue(_) if MAP_METHODS.contains(&name) => {
+                Some(BuiltInFunction::method(name, this.clone()))
+            }
+            Value::SetValue(_) if SET_METHODS.contains(&name) => {
+                Some(BuiltInFunction::method(name, this.clone()))
+            }
+            Value::IteratorValue(_) if ITERATOR_METHODS.contains(&name) => {
+                Some(BuiltInFunction::method(name, this.clone()))
+            }
+            _ => None,
+        };
+
+        member.ok_or(
+            StandardError::new(
+                format!("{} has no member '{name}'", self.object_type()).as_str(),
+                self.span(),
+                None,
+            )
+            .with_kind("NameError"),
+        )
+    }
+
+    pub fn set_member(
+        &mut self,
+        name: &str,
+        value: Rc<RefCell<Value>>,
+    ) -> Result<(), StandardError> {
+        match self {
+            Value::InstanceValue(instance) => instance.set_member(name, value),
+            _ => Err(StandardError::new(
+                "object doesn't support member assignment",
+                self.span(),
+                Some(format!("the object is a {}", self.object_type()).as_str()),
+            )
+            .with_kind("TypeError")),
         }
     }
 
+    pub fn set_index(
+        &mut self,
+        index: Rc<RefCell<Value>>,
+        value: Rc<RefCell<Value>>,
+    ) -> Result<(), StandardError> {
+        match self {
+            Value::ListValue(list) => list.set_index(&index, value),
+            Value::MapValue(map) => map.set_index(&index, value),
+            _ => Err(StandardError::new(
+                "object doesn't support index assignment",
+                self.span(),
+                Some(format!("the object is a {}", self.object_type()).as_str()),
+            )
+            .with_kind("TypeError")),
+        }
+    }
+
+    /// Returns a new list or string with the elements from `start` up to (but not including) `end`
+    ///
+    /// Missing bounds default to the start or end of the object, and a negative `step` walks backwards
+    pub fn slice(
+        &self,
+        start: Option<Rc<RefCell<Value>>>,
+        end: Option<Rc<RefCell<Value>>>,
+        step: Option<Rc<RefCell<Value>>>,
+    ) -> Result<Rc<RefCell<Value>>, StandardError> {
+        let length = match self {
+            Value::ListValue(value) => value.elements.len(),
+            Value::StringValue(value) => value.value.chars().count(),
+            _ => {
+                return Err(StandardError::new(
+                    "object is not sliceable",
+                    self.span(),
+                    Some(format!("the object is a {}", self.object_type()).as_str()),
+                )
+                .with_kind("TypeError"));
+            }
+        };
+
+        let start = Value::slice_bound(start)?;
+        let end = Value::slice_bound(end)?;
+        let step = match step {
+            Some(step) => match Value::slice_bound(Some(step.clone()))? {
+                Some(0) => {
+                    return Err(StandardError::new(
+                        "slice step cannot be zero",
+                        step.borrow().span(),
+                        None,
+                    )
+                    .with_kind("ValueError"));
+                }
+                Some(step) => step,
+                None => 1,
+            },
+            None => 1,
+        };
+
+        let positions = Value::slice_positions(length as i64, start, end, step);
+
+        match self {
+            Value::ListValue(value) => Ok(value.slice(&positions)),
+            Value::StringValue(value) => Ok(value.slice(&positions)),
+            _ => unreachable!(),
+        }
+    }
+
+    fn slice_bound(bound: Option<Rc<RefCell<Value>>>) -> Result<Option<i64>, StandardError> {
+        match bound {
+            Some(bound) => match *bound.borrow() {
+                Value::NumberValue(Number {
+                    value: NumberKind::Integer(ref number),
+                    ..
+                }) => Ok(Some(number.to_i64().unwrap_or(if number.is_negative() {
+                    i64::MIN
+                } else {
+                    i64::MAX
+                }))),
+                ref other => Err(StandardError::new(
+                    "slice bounds must be whole numbers",
+                    other.span(),
+                    None,
+                )
+                .with_kind("ValueError")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn slice_positions(length: i64, start: Option<i64>, end: Option<i64>, step: i64) -> Vec<usize> {
+        // bounds past either end are clamped, so slicing never goes out of bounds
+        let clamp = |bound: i64, low: i64, high: i64| {
+            let bound = if bound < 0 { bound + length } else { bound };
+
+            bound.clamp(low, high)
+        };
+        let mut positions = Vec::new();
+
+        if step > 0 {
+            let mut i = start.map_or(0, |s| clamp(s, 0, length));
+            let end = end.map_or(length, |e| clamp(e, 0, length));
+
+            while i < end {
+                positions.push(i as usize);
+                i = i.saturating_add(step);
+            }
+        } else {
+            let mut i = start.map_or(length - 1, |s| clamp(s, -1, length - 1));
+            let end = end.map_or(-1, |e| clamp(e, -1, length - 1));
+
+            while i > end {
+                positions.push(i as usize);
+                i = i.saturating_add(step);
+            }
+        }
+
+        positions
+    }
+
     pub fn object_type(&self) -> &str {
         match self {
+            Value::NullValue(_) => "null",
+            Value::BooleanValue(_) => "boolean",
             Value::NumberValue(_) => "number",
             Value::ListValue(_) => "list",
+            Value::MapValue(_) => "map",
+            Value::SetValue(_) => "set",
             Value::StringValue(_) => "string",
             Value::FunctionValue(_) => "function",
             Value::BuiltInFunction(_) => "built-in-function",
+            Value::IteratorValue(_) => "iterator",
+            Value::ErrorValue(_) => "error",
+            Value::BreedValue(_) => "breed",
+            Value::InstanceValue(value) => &value.breed.name,
+            Value::ModuleValue(_) => "module",
         }
     }
 
+    /// Returns whether the value counts as true in conditions and logical operators
+    ///
+    /// `null`, `false`, `0` and empty collections are false, everything else is true
     pub fn is_true(&self) -> bool {
         match self {
-            Value::NumberValue(value) => value.value != 0.0,
-            Value::ListValue(value) => value.elements.is_empty(),
-            Value::StringValue(value) => value.value.is_empty(),
-            Value::FunctionValue(value) => value.name.is_empty(),
-            Value::BuiltInFunction(value) => value.name.is_empty(),
+            Value::NullValue(_) => false,
+            Value::BooleanValue(value) => value.value,
+            Value::NumberValue(value) => !value.value.is_zero(),
+            Value::ListValue(value) => !value.elements.is_empty(),
+            Value::MapValue(value) => !value.entries.is_empty(),
+            Value::SetValue(value) => !value.elements.is_empty(),
+            Value::StringValue(value) => !value.value.is_empty(),
+            Value::FunctionValue(_) => true,
+            Value::BuiltInFunction(_) => true,
+            Value::IteratorValue(_) => true,
+            Value::ErrorValue(_) => true,
+            Value::BreedValue(_) => true,
+            Value::InstanceValue(_) => true,
+            Value::ModuleValue(_) => true,
         }
     }
 
     pub fn is_const(&self) -> bool {
         match self {
+            Value::NullValue(value) => value.is_const,
+            Value::BooleanValue(value) => value.is_const,
             Value::NumberValue(value) => value.is_const,
             Value::ListValue(value) => value.is_const,
+            Value::MapValue(value) => value.is_const,
             Value::StringValue(value) => value.is_const,
             Value::FunctionValue(value) => value.is_const,
             Value::BuiltInFunction(value) => value.is_const,
+            Value::SetValue(value) => value.is_const,
+            Value::ErrorValue(value) => value.is_const,
+            Value::IteratorValue(value) => value.is_const,
+            Value::ModuleValue(value) => value.is_const,
+            Value::BreedValue(value) => value.is_const,
+            Value::InstanceValue(value) => value.is_const,
+        }
+    }
+
+    /// Returns the key used to store the value in a set, or `None` if the value can't be hashed
+    pub fn hash_key(&self) -> Option<HashKey> {
+        match self {
+            Value::NullValue(_) => Some(HashKey::Null),
+            Value::BooleanValue(value) => Some(HashKey::Boolean(value.value)),
+            Value::NumberValue(value) => Some(match &value.value {
+                NumberKind::Integer(integer) => HashKey::Integer(integer.clone()),
+                NumberKind::Float(float) => match BigInt::from_f64(*float) {
+                    Some(integer) if float.fract() == 0.0 => HashKey::Integer(integer),
+                    _ => HashKey::Float(float.to_bits()),
+                },
+            }),
+            Value::StringValue(value) => Some(HashKey::String(value.value.clone())),
+            Value::ListValue(value) => value
+                .elements
+                .iter()
+                .map(|element| element.borrow().hash_key())
+                .collect::<Option<Vec<_>>>()
+                .map(HashKey::List),
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the value where lists inside of it are copied too
+    ///
+    /// Set elements and map keys are only hashed once, so they are stored as copies that nothing
+    /// else can change
+    pub fn frozen_copy(&self) -> Rc<RefCell<Value>> {
+        match self {
+            Value::ListValue(list) => List::from(
+                list.elements
+                    .iter()
+                    .map(|element| element.borrow().frozen_copy())
+                    .collect(),
+            ),
+            other => Rc::new(RefCell::new(other.clone())),
         }
     }
 
     pub fn as_string(&self) -> String {
         match self {
+            Value::NullValue(_) => "null".to_string(),
+            Value::BooleanValue(value) => value.value.to_string(),
             Value::NumberValue(value) => value.value.to_string(),
             Value::ListValue(value) => {
                 let output = value
@@ -140,9 +583,50 @@ impl Value {
 
                 format!("[{output}]").to_string()
             }
+            Value::MapValue(value) => {
+                let output = value
+                    .entries
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}: {}",
+                            key.borrow().as_string(),
+                            value.borrow().as_string()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("{{{output}}}")
+            }
+            Value::SetValue(value) if value.elements.is_empty() => "set()".to_string(),
+            Value::SetValue(value) => {
+                let output = value
+                    .elements
+                    .iter()
+                    .map(|(_, element)| element.borrow().as_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("{{{output}}}")
+            }
             Value::StringValue(value) => value.value.clone(),
             Value::FunctionValue(value) => format!("function: {}", value.name),
             Value::BuiltInFunction(value) => format!("built-in-function: {}", value.name),
+            Value::IteratorValue(_) => "iterator".to_string(),
+            Value::ErrorValue(value) => format!("{}: {}", value.error.kind, value.error.text),
+            Value::BreedValue(value) => format!("breed: {}", value.name),
+            Value::InstanceValue(value) => {
+                let output = value
+                    .fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value.borrow().as_string()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("{}({output})", value.breed.name)
+            }
+            Value::ModuleValue(value) => format!("module: {}", value.name),
         }
     }
 }
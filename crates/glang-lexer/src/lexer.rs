@@ -69,166 +69,150 @@ impl Lexer {
     pub fn make_tokens(&mut self) -> Result<Vec<Token>, StandardError> {
         let mut tokens = Vec::new();
 
-        while let Some(current_char) = self.current_char {
-            let pos_start = self.cursor.clone();
-
-            let token = match current_char {
-                ' ' | '\t' | '\n' => {
-                    self.advance();
+        while self.current_char.is_some() {
+            self.make_token(&mut tokens)?;
+        }
 
-                    continue;
-                }
-                '#' => {
-                    self.skip_comment();
+        tokens.push(Token::new(
+            TokenType::TT_EOF,
+            None,
+            Span::new(&self.filename, self.cursor.clone(), self.cursor.clone()),
+        ));
 
-                    continue;
-                }
-                ';' => {
-                    self.advance();
+        Ok(tokens)
+    }
 
-                    Some(Token::new(
-                        TokenType::TT_SEMICOLON,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                c if DIGITS.contains(c) => Some(self.make_number()?),
-                c if LETTERS.contains(c) => Some(self.make_identifier()),
-                '"' => Some(self.make_string()?),
-                '+' => {
-                    self.advance();
+    /// Lexes the token at the cursor, skipping whitespace and comments
+    ///
+    /// Strings can push more than one token, because interpolated expressions are lexed as normal tokens
+    fn make_token(&mut self, tokens: &mut Vec<Token>) -> Result<(), StandardError> {
+        let Some(current_char) = self.current_char else {
+            return Ok(());
+        };
 
-                    Some(Token::new(
-                        TokenType::TT_PLUS,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '-' => Some(self.make_minus_or_arrow()),
-                '*' => {
-                    self.advance();
+        let pos_start = self.cursor.clone();
 
-                    Some(Token::new(
-                        TokenType::TT_MUL,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '/' => {
-                    self.advance();
+        let token = match current_char {
+            ' ' | '\t' | '\n' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_DIV,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '^' => {
-                    self.advance();
+                return Ok(());
+            }
+            '#' => {
+                self.skip_comment();
 
-                    Some(Token::new(
-                        TokenType::TT_POW,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '%' => {
-                    self.advance();
+                return Ok(());
+            }
+            ';' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_MOD,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '(' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_SEMICOLON,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            c if DIGITS.contains(c) => Some(self.make_number()?),
+            'r' if self.peek(1) == Some('"') => return self.make_string(tokens, true),
+            c if LETTERS.contains(c) => Some(self.make_identifier()),
+            '"' => return self.make_string(tokens, false),
+            '+' => Some(self.make_plus()),
+            '-' => Some(self.make_minus_or_arrow()),
+            '*' => Some(self.make_operator(TokenType::TT_MUL, TokenType::TT_MULEQ)),
+            '/' => Some(self.make_divide()),
+            '^' => Some(self.make_operator(TokenType::TT_POW, TokenType::TT_POWEQ)),
+            '%' => Some(self.make_operator(TokenType::TT_MOD, TokenType::TT_MODEQ)),
+            '(' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_LPAREN,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                ')' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_LPAREN,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            ')' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_RPAREN,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '[' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_RPAREN,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            '[' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_LSQUARE,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                ']' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_LSQUARE,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            ']' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_RSQUARE,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '{' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_RSQUARE,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            '{' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_LBRACKET,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '}' => {
-                    self.advance();
-                    Some(Token::new(
-                        TokenType::TT_RBRACKET,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
-                '!' => Some(self.make_not_equals()?),
-                '=' => Some(self.make_equals()),
-                '<' => Some(self.make_less_than()),
-                '>' => Some(self.make_greater_than()),
-                ',' => {
-                    self.advance();
+                Some(Token::new(
+                    TokenType::TT_LBRACKET,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            '}' => {
+                self.advance();
+                Some(Token::new(
+                    TokenType::TT_RBRACKET,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            '!' => Some(self.make_not_equals()?),
+            '=' => Some(self.make_equals()),
+            '<' => Some(self.make_less_than()),
+            '>' => Some(self.make_greater_than()),
+            ',' => {
+                self.advance();
 
-                    Some(Token::new(
-                        TokenType::TT_COMMA,
-                        None,
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                    ))
-                }
+                Some(Token::new(
+                    TokenType::TT_COMMA,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            ':' => {
+                self.advance();
 
-                unknown_char => {
-                    return Err(StandardError::new(
-                        format!("unknown character '{unknown_char}'").as_str(),
-                        Span::new(&self.filename, pos_start, self.cursor.clone()),
-                        None,
-                    ));
-                }
-            };
+                Some(Token::new(
+                    TokenType::TT_COLON,
+                    None,
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                ))
+            }
+            '.' => Some(self.make_dot_or_ellipsis()),
 
-            if let Some(t) = token {
-                tokens.push(t);
+            unknown_char => {
+                return Err(StandardError::new(
+                    format!("unknown character '{unknown_char}'").as_str(),
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                    None,
+                ));
             }
-        }
+        };
 
-        tokens.push(Token::new(
-            TokenType::TT_EOF,
-            None,
-            Span::new(&self.filename, self.cursor.clone(), self.cursor.clone()),
-        ));
+        if let Some(t) = token {
+            tokens.push(t);
+        }
 
-        Ok(tokens)
+        Ok(())
     }
 
     fn make_number(&mut self) -> Result<Token, StandardError> {
@@ -301,12 +285,37 @@ impl Lexer {
         )
     }
 
-    fn make_string(&mut self) -> Result<Token, StandardError> {
+    fn make_string(&mut self, tokens: &mut Vec<Token>, is_raw: bool) -> Result<(), StandardError> {
         let mut string = String::new();
         let pos_start = self.cursor.clone();
         let mut escape_char = false;
 
-        self.advance();
+        // raw strings like r"C:\Users" have no escape characters or interpolation
+        if is_raw {
+            self.advance();
+        }
+
+        // strings starting with '"""' can span multiple lines and have their indentation removed
+        let quote_count = if self.peek(1) == Some('"') && self.peek(2) == Some('"') {
+            3
+        } else {
+            1
+        };
+
+        for _ in 0..quote_count {
+            self.advance();
+        }
+
+        let indentation = if quote_count == 3 {
+            self.multiline_indentation(is_raw)
+        } else {
+            0
+        };
+
+        // interpolated strings are split into text parts and the tokens of each expression
+        let opening_quote = Span::new(&self.filename, pos_start.clone(), self.cursor.clone());
+        let mut part_start = self.cursor.clone();
+        let mut parts: Vec<Token> = Vec::new();
 
         let mut escape_chars = HashMap::new();
         escape_chars.insert('r', '\r');
@@ -315,9 +324,18 @@ impl Lexer {
         escape_chars.insert('t', '\t');
         escape_chars.insert('\\', '\\');
         escape_chars.insert('"', '\"');
+        escape_chars.insert('{', '{');
+        escape_chars.insert('}', '}');
+
+        // a newline right after the opening quotes isn't part of the string
+        if quote_count == 3 && self.current_char == Some('\n') {
+            self.advance();
+            self.skip_indentation(indentation);
+            part_start = self.cursor.clone();
+        }
 
         while let Some(character) = self.current_char {
-            if character == '"' && !escape_char {
+            if !escape_char && self.at_closing_quotes(quote_count) {
                 break;
             }
 
@@ -360,8 +378,27 @@ impl Lexer {
                 continue;
             }
 
-            if character == '\\' {
+            if character == '\\' && !is_raw {
                 escape_char = true;
+            } else if character == '{' && !is_raw {
+                if !string.is_empty() {
+                    parts.push(Token::new(
+                        TokenType::TT_STR,
+                        Some(std::mem::take(&mut string)),
+                        Span::new(&self.filename, part_start, self.cursor.clone()),
+                    ));
+                }
+
+                self.make_interpolated_expr(&mut parts)?;
+                part_start = self.cursor.clone();
+
+                continue;
+            } else if character == '\n' && quote_count == 3 {
+                string.push('\n');
+                self.advance();
+                self.skip_indentation(indentation);
+
+                continue;
             } else {
                 string.push(character);
             }
@@ -369,35 +406,267 @@ impl Lexer {
             self.advance();
         }
 
-        if self.current_char != Some('"') {
+        if self.current_char.is_none() {
             return Err(StandardError::new(
                 "unfinished string",
                 Span::new(&self.filename, pos_start, self.cursor.clone()),
-                Some("add a '\"' at the end of the string to close it"),
+                Some(if quote_count == 3 {
+                    "add a '\"\"\"' at the end of the string to close it"
+                } else {
+                    "add a '\"' at the end of the string to close it"
+                }),
             ));
         }
 
+        // the line with the closing quotes isn't part of the string either
+        if quote_count == 3
+            && let Some(newline) = string.rfind('\n')
+            && string[newline + 1..].chars().all(|c| c == ' ' || c == '\t')
+        {
+            string.truncate(newline);
+        }
+
+        let closing_quote_start = self.cursor.clone();
+
+        for _ in 0..quote_count {
+            self.advance();
+        }
+
+        let pos_end = self.cursor.clone();
+
+        if parts.is_empty() {
+            tokens.push(Token::new(
+                TokenType::TT_STR,
+                Some(string),
+                Span::new(&self.filename, pos_start, pos_end),
+            ));
+
+            return Ok(());
+        }
+
+        if !string.is_empty() {
+            parts.push(Token::new(
+                TokenType::TT_STR,
+                Some(string),
+                Span::new(&self.filename, part_start, closing_quote_start.clone()),
+            ));
+        }
+
+        tokens.push(Token::new(
+            TokenType::TT_INTERPOLATION_START,
+            None,
+            opening_quote,
+        ));
+        tokens.append(&mut parts);
+        tokens.push(Token::new(
+            TokenType::TT_INTERPOLATION_END,
+            None,
+            Span::new(&self.filename, closing_quote_start, pos_end),
+        ));
+
+        Ok(())
+    }
+
+    /// Returns the smallest indentation of the lines in a multi-line string, ignoring blank lines
+    ///
+    /// The first line is ignored too, because it starts right after the opening quotes
+    fn multiline_indentation(&self, is_raw: bool) -> usize {
+        let mut indentation: Option<usize> = None;
+        let mut line_indentation = 0;
+        let mut at_line_start = false;
+        let mut index = self.cursor.index;
+
+        while index < self.chars.len() {
+            let character = self.chars[index];
+
+            if character == '\\' && !is_raw {
+                at_line_start = false;
+                index += 2;
+
+                continue;
+            }
+
+            if self.chars[index..].starts_with(&['"', '"', '"']) {
+                break;
+            }
+
+            if character == '\n' {
+                at_line_start = true;
+                line_indentation = 0;
+            } else if at_line_start && (character == ' ' || character == '\t') {
+                line_indentation += 1;
+            } else if at_line_start {
+                indentation =
+                    Some(indentation.map_or(line_indentation, |i| i.min(line_indentation)));
+                at_line_start = false;
+            }
+
+            index += 1;
+        }
+
+        indentation.unwrap_or(0)
+    }
+
+    fn skip_indentation(&mut self, indentation: usize) {
+        for _ in 0..indentation {
+            if !matches!(self.current_char, Some(' ') | Some('\t')) {
+                break;
+            }
+
+            self.advance();
+        }
+    }
+
+    fn at_closing_quotes(&self, quote_count: usize) -> bool {
+        (0..quote_count).all(|offset| self.peek(offset) == Some('"'))
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.cursor.index + offset).copied()
+    }
+
+    /// Lexes an interpolated expression like `{age + 1}`, including its brackets
+    fn make_interpolated_expr(&mut self, tokens: &mut Vec<Token>) -> Result<(), StandardError> {
+        let pos_start = self.cursor.clone();
+        let mut depth = 0;
+
+        loop {
+            if self.current_char.is_none() {
+                return Err(StandardError::new(
+                    "unfinished string interpolation",
+                    Span::new(&self.filename, pos_start, self.cursor.clone()),
+                    Some("add a '}' to close the interpolated expression"),
+                ));
+            }
+
+            let token_count = tokens.len();
+
+            self.make_token(tokens)?;
+
+            // only brackets lexed on their own count, the ones inside of nested strings are already matched
+            if tokens.len() == token_count + 1 {
+                match tokens[token_count].token_type {
+                    TokenType::TT_LBRACKET => depth += 1,
+                    TokenType::TT_RBRACKET => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Ok(());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Makes an operator token, or its compound assignment version when it is followed by '='
+    fn make_operator(&mut self, token_type: TokenType, assign_type: TokenType) -> Token {
+        let mut token_type = token_type;
+        let pos_start = self.cursor.clone();
         self.advance();
 
+        if self.current_char == Some('=') {
+            self.advance();
+            token_type = assign_type;
+        }
+
         let pos_end = self.cursor.clone();
 
-        Ok(Token::new(
-            TokenType::TT_STR,
-            Some(string),
+        Token::new(
+            token_type,
+            None,
             Span::new(&self.filename, pos_start, pos_end),
-        ))
+        )
+    }
+
+    fn make_plus(&mut self) -> Token {
+        if self.is_step_operator('+') {
+            return self.make_step_operator(TokenType::TT_INCREMENT);
+        }
+
+        self.make_operator(TokenType::TT_PLUS, TokenType::TT_PLUSEQ)
     }
 
     fn make_minus_or_arrow(&mut self) -> Token {
-        let mut token_type = TokenType::TT_MINUS;
+        if self.is_step_operator('-') {
+            return self.make_step_operator(TokenType::TT_DECREMENT);
+        }
+
+        if self.peek(1) != Some('>') {
+            return self.make_operator(TokenType::TT_MINUS, TokenType::TT_MINUSEQ);
+        }
+
         let pos_start = self.cursor.clone();
         self.advance();
+        self.advance();
 
-        if let Some(character) = self.current_char
-            && character == '>'
-        {
+        let pos_end = self.cursor.clone();
+
+        Token::new(
+            TokenType::TT_ARROW,
+            None,
+            Span::new(&self.filename, pos_start, pos_end),
+        )
+    }
+
+    /// Returns whether the cursor is on a '++' or '--' that ends a statement, like in `count++;`
+    ///
+    /// Anything else after it is an operand, so `5--3` still subtracts a negative number
+    fn is_step_operator(&self, character: char) -> bool {
+        if self.current_char != Some(character) || self.peek(1) != Some(character) {
+            return false;
+        }
+
+        let mut offset = 2;
+
+        while matches!(self.peek(offset), Some(' ' | '\t')) {
+            offset += 1;
+        }
+
+        matches!(
+            self.peek(offset),
+            None | Some(';' | '\n' | ')' | ']' | '}' | ',' | '#')
+        )
+    }
+
+    fn make_step_operator(&mut self, token_type: TokenType) -> Token {
+        let pos_start = self.cursor.clone();
+        self.advance();
+        self.advance();
+
+        let pos_end = self.cursor.clone();
+
+        Token::new(
+            token_type,
+            None,
+            Span::new(&self.filename, pos_start, pos_end),
+        )
+    }
+
+    fn make_divide(&mut self) -> Token {
+        if self.peek(1) == Some('/') {
+            let pos_start = self.cursor.clone();
             self.advance();
-            token_type = TokenType::TT_ARROW;
+
+            let mut token = self.make_operator(TokenType::TT_FLOORDIV, TokenType::TT_FLOORDIVEQ);
+            token.span.start = pos_start;
+
+            return token;
+        }
+
+        self.make_operator(TokenType::TT_DIV, TokenType::TT_DIVEQ)
+    }
+
+    fn make_dot_or_ellipsis(&mut self) -> Token {
+        let mut token_type = TokenType::TT_DOT;
+        let pos_start = self.cursor.clone();
+        self.advance();
+
+        if self.current_char == Some('.') && self.peek(1) == Some('.') {
+            self.advance();
+            self.advance();
+            token_type = TokenType::TT_ELLIPSIS;
         }
 
         let pos_end = self.cursor.clone();
@@ -530,3 +799,44 @@ fn test_tokens() {
     assert_eq!(tokens[6].token_type, TokenType::TT_SEMICOLON);
     assert_eq!(tokens[7].token_type, TokenType::TT_EOF);
 }
+
+#[test]
+fn test_interpolation_tokens() {
+    let mut lexer = Lexer::new(Path::new("<test>"), "\"hi {name}!\"");
+    let tokens = lexer.make_tokens().ok().unwrap();
+
+    assert_eq!(tokens.len(), 8); // including EOF token
+    assert_eq!(tokens[0].token_type, TokenType::TT_INTERPOLATION_START);
+    assert_eq!(tokens[1].token_type, TokenType::TT_STR);
+    assert_eq!(tokens[1].value, "hi ");
+    assert_eq!(tokens[2].token_type, TokenType::TT_LBRACKET);
+    assert_eq!(tokens[3].token_type, TokenType::TT_IDENTIFIER);
+    assert_eq!(tokens[4].token_type, TokenType::TT_RBRACKET);
+    assert_eq!(tokens[5].token_type, TokenType::TT_STR);
+    assert_eq!(tokens[6].token_type, TokenType::TT_INTERPOLATION_END);
+    assert_eq!(tokens[7].token_type, TokenType::TT_EOF);
+}
+
+#[test]
+fn test_ellipsis_tokens() {
+    let mut lexer = Lexer::new(Path::new("<test>"), "(a.b, ...rest)");
+    let tokens = lexer.make_tokens().ok().unwrap();
+
+    assert_eq!(tokens.len(), 9); // including EOF token
+    assert_eq!(tokens[2].token_type, TokenType::TT_DOT);
+    assert_eq!(tokens[5].token_type, TokenType::TT_ELLIPSIS);
+    assert_eq!(tokens[6].token_type, TokenType::TT_IDENTIFIER);
+    assert_eq!(tokens[6].value, "rest");
+}
+
+#[test]
+fn test_compound_assignment_tokens() {
+    let mut lexer = Lexer::new(Path::new("<test>"), "a += 1; b //= 2; c++; 5--3");
+    let tokens = lexer.make_tokens().ok().unwrap();
+
+    assert_eq!(tokens[1].token_type, TokenType::TT_PLUSEQ);
+    assert_eq!(tokens[5].token_type, TokenType::TT_FLOORDIVEQ);
+    assert_eq!(tokens[9].token_type, TokenType::TT_INCREMENT);
+    assert_eq!(tokens[12].token_type, TokenType::TT_MINUS);
+    assert_eq!(tokens[13].token_type, TokenType::TT_MINUS);
+}
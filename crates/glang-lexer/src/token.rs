@@ -5,14 +5,26 @@ use glang_attributes::Span;
 pub enum TokenType {
     TT_NUM,
     TT_STR,
+    TT_INTERPOLATION_START,
+    TT_INTERPOLATION_END,
     TT_IDENTIFIER,
     TT_KEYWORD,
     TT_PLUS,
     TT_MINUS,
     TT_MUL,
     TT_DIV,
+    TT_FLOORDIV,
     TT_POW,
     TT_MOD,
+    TT_PLUSEQ,
+    TT_MINUSEQ,
+    TT_MULEQ,
+    TT_DIVEQ,
+    TT_FLOORDIVEQ,
+    TT_POWEQ,
+    TT_MODEQ,
+    TT_INCREMENT,
+    TT_DECREMENT,
     TT_EQ,
     TT_LPAREN,
     TT_RPAREN,
@@ -27,6 +39,9 @@ pub enum TokenType {
     TT_LTE,
     TT_GTE,
     TT_COMMA,
+    TT_COLON,
+    TT_DOT,
+    TT_ELLIPSIS,
     TT_ARROW,
     TT_SEMICOLON,
     TT_EOF,
@@ -29,6 +29,7 @@ impl AstArena {
                 TokenType::TT_MINUS => "-",
                 TokenType::TT_MUL => "*",
                 TokenType::TT_DIV => "/",
+                TokenType::TT_FLOORDIV => "//",
                 TokenType::TT_POW => "^",
                 TokenType::TT_MOD => "%",
                 TokenType::TT_GT => ">",
@@ -50,19 +51,45 @@ impl AstArena {
         }))
     }
 
+    pub fn boolean_node(&mut self, token: Token) -> NodeID {
+        self.add(AstNode::Boolean(BooleanNode {
+            value: token.value == "true",
+            span: token.span,
+        }))
+    }
+
     pub fn break_node(&mut self, span: Span) -> NodeID {
         self.add(AstNode::Break(BreakNode { span }))
     }
 
+    pub fn breed_definition_node(
+        &mut self,
+        name_token: Token,
+        method_nodes: Vec<NodeID>,
+        closing_bracket: Token,
+    ) -> NodeID {
+        self.add(AstNode::BreedDefinition(BreedDefinitionNode {
+            name: name_token.value,
+            method_nodes,
+            span: Span::new(
+                &name_token.span.filename,
+                name_token.span.start,
+                closing_bracket.span.end,
+            ),
+        }))
+    }
+
     pub fn call_node(
         &mut self,
         node_to_call: NodeID,
         arg_nodes: Vec<NodeID>,
+        keyword_arg_nodes: Vec<(Token, NodeID)>,
         closing_bracket: Token,
     ) -> NodeID {
         self.add(AstNode::Call(CallNode {
             node_to_call,
             arg_nodes,
+            keyword_arg_nodes,
             span: Span::new(
                 &self.span(node_to_call).filename,
                 self.position_start(node_to_call),
@@ -83,6 +110,14 @@ impl AstArena {
         self.add(AstNode::Continue(ContinueNode { span }))
     }
 
+    pub fn destructure_node(&mut self, pattern: Pattern, value_node: NodeID, span: Span) -> NodeID {
+        self.add(AstNode::Destructure(DestructureNode {
+            pattern,
+            value_node,
+            span,
+        }))
+    }
+
     pub fn for_node(
         &mut self,
         var_name_token: Token,
@@ -103,24 +138,31 @@ impl AstArena {
 
     pub fn for_each_node(
         &mut self,
-        var_name_token: Token,
+        iterator_pattern: Pattern,
         iterator: NodeID,
         body_node: NodeID,
+        span: Span,
     ) -> NodeID {
         self.add(AstNode::ForEach(ForEachNode {
-            iterator_name: var_name_token.value,
+            iterator_pattern,
             iterator_node: iterator,
             body_node,
-            span: var_name_token.span,
+            span,
         }))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn function_definition_node(
         &mut self,
         var_name_token: Option<Token>,
         arg_name_tokens: &[Token],
+        arg_type_tokens: Vec<Option<Token>>,
+        default_nodes: Vec<Option<NodeID>>,
+        rest_arg_name_token: Option<Token>,
+        return_type_token: Option<Token>,
         body_node: NodeID,
         should_auto_return: bool,
+        is_generator: bool,
     ) -> NodeID {
         self.add(AstNode::FunctionDefinition(FunctionDefinitionNode {
             name: if let Some(ref tok) = var_name_token {
@@ -129,14 +171,21 @@ impl AstArena {
                 None
             },
             argument_names: arg_name_tokens.to_vec(),
+            argument_types: arg_type_tokens,
+            default_nodes,
+            rest_argument_name: rest_arg_name_token.clone(),
+            return_type: return_type_token,
             body_node: body_node,
             should_auto_return,
+            is_generator,
             span: Span::new(
                 &self.span(body_node).filename,
                 if let Some(var_name) = var_name_token {
                     var_name.span.end
                 } else if !arg_name_tokens.is_empty() {
                     arg_name_tokens[0].span.start.clone()
+                } else if let Some(rest_arg_name) = rest_arg_name_token {
+                    rest_arg_name.span.start
                 } else {
                     self.position_end(body_node)
                 },
@@ -165,13 +214,68 @@ impl AstArena {
         }))
     }
 
-    pub fn import_node(&mut self, node_to_import: NodeID) -> NodeID {
+    pub fn import_node(
+        &mut self,
+        node_to_import: NodeID,
+        alias: Option<Token>,
+        names: Vec<Token>,
+    ) -> NodeID {
         self.add(AstNode::Import(ImportNode {
             node_to_import,
+            alias,
+            names,
             span: self.span(node_to_import),
         }))
     }
 
+    pub fn index_node(
+        &mut self,
+        node_to_index: NodeID,
+        index_node: NodeID,
+        closing_bracket: Token,
+    ) -> NodeID {
+        self.add(AstNode::Index(IndexNode {
+            node_to_index,
+            index_node,
+            span: Span::new(
+                &self.span(node_to_index).filename,
+                self.position_start(node_to_index),
+                closing_bracket.span.end,
+            ),
+        }))
+    }
+
+    pub fn index_assign_node(
+        &mut self,
+        target: IndexNode,
+        value_node: NodeID,
+        operator: Option<&str>,
+    ) -> NodeID {
+        self.add(AstNode::IndexAssign(IndexAssignNode {
+            node_to_index: target.node_to_index,
+            index_node: target.index_node,
+            value_node,
+            operator: operator.map(str::to_owned),
+            span: target.span,
+        }))
+    }
+
+    pub fn interpolation_node(
+        &mut self,
+        part_nodes: Vec<NodeID>,
+        opening_quote: Token,
+        closing_quote: Token,
+    ) -> NodeID {
+        self.add(AstNode::Interpolation(InterpolationNode {
+            part_nodes,
+            span: Span::new(
+                &opening_quote.span.filename,
+                opening_quote.span.start,
+                closing_quote.span.end,
+            ),
+        }))
+    }
+
     pub fn list_node(&mut self, element_nodes: Vec<NodeID>, span: Span) -> NodeID {
         self.add(AstNode::List(ListNode {
             element_nodes,
@@ -179,9 +283,56 @@ impl AstArena {
         }))
     }
 
+    pub fn map_node(&mut self, pairs: Vec<(NodeID, NodeID)>, span: Span) -> NodeID {
+        self.add(AstNode::Map(MapNode { pairs, span }))
+    }
+
+    pub fn match_node(&mut self, value_node: NodeID, arms: Vec<MatchArm>, span: Span) -> NodeID {
+        self.add(AstNode::Match(MatchNode {
+            value_node,
+            arms,
+            span,
+        }))
+    }
+
+    pub fn member_access_node(
+        &mut self,
+        node_to_access: NodeID,
+        member_name_token: Token,
+    ) -> NodeID {
+        self.add(AstNode::MemberAccess(MemberAccessNode {
+            node_to_access,
+            member_name: member_name_token.value,
+            span: Span::new(
+                &self.span(node_to_access).filename,
+                self.position_start(node_to_access),
+                member_name_token.span.end,
+            ),
+        }))
+    }
+
+    pub fn member_assign_node(
+        &mut self,
+        target: MemberAccessNode,
+        value_node: NodeID,
+        operator: Option<&str>,
+    ) -> NodeID {
+        self.add(AstNode::MemberAssign(MemberAssignNode {
+            node_to_access: target.node_to_access,
+            member_name: target.member_name,
+            value_node,
+            operator: operator.map(str::to_owned),
+            span: target.span,
+        }))
+    }
+
+    pub fn null_node(&mut self, token: Token) -> NodeID {
+        self.add(AstNode::Null(NullNode { span: token.span }))
+    }
+
     pub fn number_node(&mut self, token: Token) -> NodeID {
         self.add(AstNode::Number(NumberNode {
-            value: token.value.parse::<f64>().unwrap(),
+            value: token.value,
             span: token.span,
         }))
     }
@@ -193,6 +344,27 @@ impl AstArena {
         }))
     }
 
+    pub fn slice_node(
+        &mut self,
+        node_to_slice: NodeID,
+        start_node: Option<NodeID>,
+        end_node: Option<NodeID>,
+        step_node: Option<NodeID>,
+        closing_bracket: Token,
+    ) -> NodeID {
+        self.add(AstNode::Slice(SliceNode {
+            node_to_slice,
+            start_node,
+            end_node,
+            step_node,
+            span: Span::new(
+                &self.span(node_to_slice).filename,
+                self.position_start(node_to_slice),
+                closing_bracket.span.end,
+            ),
+        }))
+    }
+
     pub fn string_node(&mut self, token: Token) -> NodeID {
         self.add(AstNode::Strings(StringNode {
             value: token.value,
@@ -203,17 +375,21 @@ impl AstArena {
     pub fn try_except_node(
         &mut self,
         try_body_node: NodeID,
-        except_body_node: NodeID,
-        error_name_token: Token,
+        catch_clauses: Vec<CatchClause>,
+        finally_body_node: Option<NodeID>,
     ) -> NodeID {
+        let last_body_node = finally_body_node
+            .or(catch_clauses.last().map(|clause| clause.body_node))
+            .unwrap_or(try_body_node);
+
         self.add(AstNode::TryExcept(TryExceptNode {
             try_body_node: try_body_node,
-            except_body_node: except_body_node,
-            passed_error: error_name_token.value,
+            catch_clauses,
+            finally_body_node,
             span: Span::new(
                 &self.span(try_body_node).filename,
                 self.position_start(try_body_node),
-                self.position_end(except_body_node),
+                self.position_end(last_body_node),
             ),
         }))
     }
@@ -248,18 +424,32 @@ impl AstArena {
         }))
     }
 
-    pub fn variable_assign_node(&mut self, var_name_token: Token, value_node: NodeID) -> NodeID {
+    pub fn variable_assign_node(
+        &mut self,
+        var_name_token: Token,
+        type_token: Option<Token>,
+        value_node: NodeID,
+    ) -> NodeID {
         self.add(AstNode::VariableAssign(VariableAssignNode {
             name: var_name_token.value,
+            type_annotation: type_token,
             value_node,
             span: var_name_token.span,
         }))
     }
 
-    pub fn variable_reassign_node(&mut self, var_name_token: Token, value_node: NodeID) -> NodeID {
+    pub fn variable_reassign_node(
+        &mut self,
+        var_name_token: Token,
+        value_node: NodeID,
+        operator: Option<&str>,
+        is_nonlocal: bool,
+    ) -> NodeID {
         self.add(AstNode::VariableReassign(VariableRessignNode {
             name: var_name_token.value,
             value_node,
+            operator: operator.map(str::to_owned),
+            is_nonlocal,
             span: var_name_token.span,
         }))
     }
@@ -276,6 +466,13 @@ impl AstArena {
         }))
     }
 
+    pub fn yield_node(&mut self, node_to_yield: Option<NodeID>, span: Span) -> NodeID {
+        self.add(AstNode::Yield(YieldNode {
+            node_to_yield,
+            span,
+        }))
+    }
+
     pub fn add(&mut self, node: AstNode) -> NodeID {
         let id = NodeID(self.nodes.len());
         self.nodes.push(node);
@@ -299,18 +496,30 @@ impl AstArena {
 #[derive(Debug, Clone)]
 pub enum AstNode {
     BinaryOperator(BinaryOperatorNode),
+    Boolean(BooleanNode),
     Break(BreakNode),
+    BreedDefinition(BreedDefinitionNode),
     Call(CallNode),
     ConstAssign(ConstAssignNode),
     Continue(ContinueNode),
+    Destructure(DestructureNode),
     For(ForNode),
     ForEach(ForEachNode),
     FunctionDefinition(FunctionDefinitionNode),
     If(IfNode),
     Import(ImportNode),
+    Index(IndexNode),
+    IndexAssign(IndexAssignNode),
+    Interpolation(InterpolationNode),
     List(ListNode),
+    Map(MapNode),
+    Match(MatchNode),
+    MemberAccess(MemberAccessNode),
+    MemberAssign(MemberAssignNode),
+    Null(NullNode),
     Number(NumberNode),
     Return(ReturnNode),
+    Slice(SliceNode),
     Strings(StringNode),
     TryExcept(TryExceptNode),
     UnaryOperator(UnaryOperatorNode),
@@ -318,24 +527,37 @@ pub enum AstNode {
     VariableAssign(VariableAssignNode),
     VariableReassign(VariableRessignNode),
     While(WhileNode),
+    Yield(YieldNode),
 }
 
 impl AstNode {
     pub fn span(&self) -> Span {
         match self {
             AstNode::BinaryOperator(node) => node.span.clone(),
+            AstNode::Boolean(node) => node.span.clone(),
             AstNode::Break(node) => node.span.clone(),
+            AstNode::BreedDefinition(node) => node.span.clone(),
             AstNode::Call(node) => node.span.clone(),
             AstNode::ConstAssign(node) => node.span.clone(),
             AstNode::Continue(node) => node.span.clone(),
+            AstNode::Destructure(node) => node.span.clone(),
             AstNode::For(node) => node.span.clone(),
             AstNode::ForEach(node) => node.span.clone(),
             AstNode::FunctionDefinition(node) => node.span.clone(),
             AstNode::If(node) => node.span.clone(),
             AstNode::Import(node) => node.span.clone(),
+            AstNode::Index(node) => node.span.clone(),
+            AstNode::IndexAssign(node) => node.span.clone(),
+            AstNode::Interpolation(node) => node.span.clone(),
             AstNode::List(node) => node.span.clone(),
+            AstNode::Map(node) => node.span.clone(),
+            AstNode::Match(node) => node.span.clone(),
+            AstNode::MemberAccess(node) => node.span.clone(),
+            AstNode::MemberAssign(node) => node.span.clone(),
+            AstNode::Null(node) => node.span.clone(),
             AstNode::Number(node) => node.span.clone(),
             AstNode::Return(node) => node.span.clone(),
+            AstNode::Slice(node) => node.span.clone(),
             AstNode::Strings(node) => node.span.clone(),
             AstNode::TryExcept(node) => node.span.clone(),
             AstNode::UnaryOperator(node) => node.span.clone(),
@@ -343,24 +565,37 @@ impl AstNode {
             AstNode::VariableAssign(node) => node.span.clone(),
             AstNode::VariableReassign(node) => node.span.clone(),
             AstNode::While(node) => node.span.clone(),
+            AstNode::Yield(node) => node.span.clone(),
         }
     }
 
     pub fn position_start(&self) -> Position {
         match self {
             AstNode::BinaryOperator(node) => node.span.start.clone(),
+            AstNode::Boolean(node) => node.span.start.clone(),
             AstNode::Break(node) => node.span.start.clone(),
+            AstNode::BreedDefinition(node) => node.span.start.clone(),
             AstNode::Call(node) => node.span.start.clone(),
             AstNode::ConstAssign(node) => node.span.start.clone(),
             AstNode::Continue(node) => node.span.start.clone(),
+            AstNode::Destructure(node) => node.span.start.clone(),
             AstNode::For(node) => node.span.start.clone(),
             AstNode::ForEach(node) => node.span.start.clone(),
             AstNode::FunctionDefinition(node) => node.span.start.clone(),
             AstNode::If(node) => node.span.start.clone(),
             AstNode::Import(node) => node.span.start.clone(),
+            AstNode::Index(node) => node.span.start.clone(),
+            AstNode::IndexAssign(node) => node.span.start.clone(),
+            AstNode::Interpolation(node) => node.span.start.clone(),
             AstNode::List(node) => node.span.start.clone(),
+            AstNode::Map(node) => node.span.start.clone(),
+            AstNode::Match(node) => node.span.start.clone(),
+            AstNode::MemberAccess(node) => node.span.start.clone(),
+            AstNode::MemberAssign(node) => node.span.start.clone(),
+            AstNode::Null(node) => node.span.start.clone(),
             AstNode::Number(node) => node.span.start.clone(),
             AstNode::Return(node) => node.span.start.clone(),
+            AstNode::Slice(node) => node.span.start.clone(),
             AstNode::Strings(node) => node.span.start.clone(),
             AstNode::TryExcept(node) => node.span.start.clone(),
             AstNode::UnaryOperator(node) => node.span.start.clone(),
@@ -368,24 +603,37 @@ impl AstNode {
             AstNode::VariableAssign(node) => node.span.start.clone(),
             AstNode::VariableReassign(node) => node.span.start.clone(),
             AstNode::While(node) => node.span.start.clone(),
+            AstNode::Yield(node) => node.span.start.clone(),
         }
     }
 
     pub fn position_end(&self) -> Position {
         match self {
             AstNode::BinaryOperator(node) => node.span.end.clone(),
+            AstNode::Boolean(node) => node.span.end.clone(),
             AstNode::Break(node) => node.span.end.clone(),
+            AstNode::BreedDefinition(node) => node.span.end.clone(),
             AstNode::Call(node) => node.span.end.clone(),
             AstNode::ConstAssign(node) => node.span.end.clone(),
             AstNode::Continue(node) => node.span.end.clone(),
+            AstNode::Destructure(node) => node.span.end.clone(),
             AstNode::For(node) => node.span.end.clone(),
             AstNode::ForEach(node) => node.span.end.clone(),
             AstNode::FunctionDefinition(node) => node.span.end.clone(),
             AstNode::If(node) => node.span.end.clone(),
             AstNode::Import(node) => node.span.end.clone(),
+            AstNode::Index(node) => node.span.end.clone(),
+            AstNode::IndexAssign(node) => node.span.end.clone(),
+            AstNode::Interpolation(node) => node.span.end.clone(),
             AstNode::List(node) => node.span.end.clone(),
+            AstNode::Map(node) => node.span.end.clone(),
+            AstNode::Match(node) => node.span.end.clone(),
+            AstNode::MemberAccess(node) => node.span.end.clone(),
+            AstNode::MemberAssign(node) => node.span.end.clone(),
+            AstNode::Null(node) => node.span.end.clone(),
             AstNode::Number(node) => node.span.end.clone(),
             AstNode::Return(node) => node.span.end.clone(),
+            AstNode::Slice(node) => node.span.end.clone(),
             AstNode::Strings(node) => node.span.end.clone(),
             AstNode::TryExcept(node) => node.span.end.clone(),
             AstNode::UnaryOperator(node) => node.span.end.clone(),
@@ -393,6 +641,7 @@ impl AstNode {
             AstNode::VariableAssign(node) => node.span.end.clone(),
             AstNode::VariableReassign(node) => node.span.end.clone(),
             AstNode::While(node) => node.span.end.clone(),
+            AstNode::Yield(node) => node.span.end.clone(),
         }
     }
 }
@@ -405,15 +654,30 @@ pub struct BinaryOperatorNode {
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct BooleanNode {
+    pub value: bool,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct BreakNode {
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct BreedDefinitionNode {
+    pub name: String,
+    pub method_nodes: Vec<NodeID>,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct CallNode {
     pub node_to_call: NodeID,
     pub arg_nodes: Vec<NodeID>,
+    pub keyword_arg_nodes: Vec<(Token, NodeID)>, // arguments given by name, like 'greeting = "hi"'
+
     pub span: Span,
 }
 
@@ -429,6 +693,13 @@ pub struct ContinueNode {
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct DestructureNode {
+    pub pattern: Pattern, // the names to unpack the value into, like '[head, ...rest]'
+    pub value_node: NodeID,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct ForNode {
     pub iterator_name: String,
@@ -441,7 +712,7 @@ pub struct ForNode {
 
 #[derive(Debug, Clone)]
 pub struct ForEachNode {
-    pub iterator_name: String,
+    pub iterator_pattern: Pattern, // a name, or a list of names to unpack each value into
     pub iterator_node: NodeID,
     pub body_node: NodeID,
     pub span: Span,
@@ -451,8 +722,13 @@ pub struct ForEachNode {
 pub struct FunctionDefinitionNode {
     pub name: Option<String>,
     pub argument_names: Vec<Token>,
+    pub argument_types: Vec<Option<Token>>, // one for each argument name, only read by 'glang check'
+    pub default_nodes: Vec<Option<NodeID>>, // one for each argument name
+    pub rest_argument_name: Option<Token>,
+    pub return_type: Option<Token>,
     pub body_node: NodeID,
     pub should_auto_return: bool,
+    pub is_generator: bool, // set when the body contains a 'toss'
     pub span: Span,
 }
 
@@ -466,6 +742,30 @@ pub struct IfNode {
 #[derive(Debug, Clone)]
 pub struct ImportNode {
     pub node_to_import: NodeID,
+    pub alias: Option<Token>, // 'fetch std_math as math;' only binds the module itself
+    pub names: Vec<Token>,    // 'fetch sqrt, math_pi from std_math;' only binds these members
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexNode {
+    pub node_to_index: NodeID,
+    pub index_node: NodeID,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexAssignNode {
+    pub node_to_index: NodeID,
+    pub index_node: NodeID,
+    pub value_node: NodeID,
+    pub operator: Option<String>, // set for compound assignments like '+=', which apply it to the old value
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct InterpolationNode {
+    pub part_nodes: Vec<NodeID>,
     pub span: Span,
 }
 
@@ -475,9 +775,60 @@ pub struct ListNode {
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct MapNode {
+    pub pairs: Vec<(NodeID, NodeID)>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchNode {
+    pub value_node: NodeID,
+    pub arms: Vec<MatchArm>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard_node: Option<NodeID>,
+    pub body_node: NodeID,
+    pub should_return_null: bool,
+}
+
+/// The shape a value is checked against in a 'sniff' arm
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Literal(NodeID),                   // a number, string, boolean or null, like '0'
+    Binding(Token),                    // any value, saved under the name unless it is '_'
+    Type(Token, Token),                // a value of the type, saved under the name, like 'number n'
+    List(Vec<Pattern>, Option<Token>), // a list of matching elements, like '[first, ...rest]'
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberAccessNode {
+    pub node_to_access: NodeID,
+    pub member_name: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct MemberAssignNode {
+    pub node_to_access: NodeID,
+    pub member_name: String,
+    pub value_node: NodeID,
+    pub operator: Option<String>, // set for compound assignments like '+=', which apply it to the old value
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub struct NullNode {
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct NumberNode {
-    pub value: f64,
+    pub value: String, // kept as written so that large integers stay exact
     pub span: Span,
 }
 
@@ -487,6 +838,15 @@ pub struct ReturnNode {
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct SliceNode {
+    pub node_to_slice: NodeID,
+    pub start_node: Option<NodeID>,
+    pub end_node: Option<NodeID>,
+    pub step_node: Option<NodeID>,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone)]
 pub struct StringNode {
     pub value: String,
@@ -496,11 +856,18 @@ pub struct StringNode {
 #[derive(Debug, Clone)]
 pub struct TryExceptNode {
     pub try_body_node: NodeID,
-    pub except_body_node: NodeID,
-    pub passed_error: String,
+    pub catch_clauses: Vec<CatchClause>, // checked top to bottom, only the first match runs
+    pub finally_body_node: Option<NodeID>,
     pub span: Span,
 }
 
+#[derive(Debug, Clone)]
+pub struct CatchClause {
+    pub kind: Option<String>, // catches every kind of error when there isn't one
+    pub passed_error: String,
+    pub body_node: NodeID,
+}
+
 #[derive(Debug, Clone)]
 pub struct UnaryOperatorNode {
     pub operator: String,
@@ -517,6 +884,7 @@ pub struct VariableAccessNode {
 #[derive(Debug, Clone)]
 pub struct VariableAssignNode {
     pub name: String,
+    pub type_annotation: Option<Token>, // only read by 'glang check', like 'number' in 'obj count: number = 0;'
     pub value_node: NodeID,
     pub span: Span,
 }
@@ -525,6 +893,8 @@ pub struct VariableAssignNode {
 pub struct VariableRessignNode {
     pub name: String,
     pub value_node: NodeID,
+    pub operator: Option<String>, // set for compound assignments like '+=', which apply it to the old value
+    pub is_nonlocal: bool, // set for 'nonlocal x = ...', which rebinds x where it was defined
     pub span: Span,
 }
 
@@ -534,3 +904,9 @@ pub struct WhileNode {
     pub body_node: NodeID,
     pub span: Span,
 }
+
+#[derive(Debug, Clone)]
+pub struct YieldNode {
+    pub node_to_yield: Option<NodeID>,
+    pub span: Span,
+}
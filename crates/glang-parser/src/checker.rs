@@ -0,0 +1,828 @@
+use crate::ast_node::{AstArena, AstNode, FunctionDefinitionNode, NodeID, Pattern};
+use glang_attributes::{Span, StandardError};
+use glang_lexer::Token;
+use std::collections::{HashMap, HashSet};
+
+/// The types that can be written in an annotation, besides the names of breeds
+const TYPE_NAMES: &[&str] = &[
+    "any", "number", "string", "boolean", "null", "list", "map", "set", "function", "breed",
+    "iterator", "error", "module",
+];
+
+/// Checks the type annotations of a program without running it, returning every mismatch found
+///
+/// Types are only inferred where they can't change at runtime, so anything the checker isn't sure
+/// about is allowed rather than reported
+pub fn check(arena: &AstArena) -> Vec<StandardError> {
+    let mut checker = Checker::new(arena);
+
+    if !arena.nodes.is_empty() {
+        checker.infer(NodeID(arena.nodes.len() - 1));
+    }
+
+    checker.errors
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Signature {
+    arguments: Vec<(String, Option<String>)>,
+    return_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Symbol {
+    annotation: Option<String>, // a declared type is checked on every assignment, so it is trusted
+    inferred: Option<String>,   // only known while every assignment so far gave the same type
+    signature: Option<Signature>,
+}
+
+impl Symbol {
+    fn of_type(inferred: Option<String>) -> Self {
+        Self {
+            inferred,
+            ..Default::default()
+        }
+    }
+}
+
+struct Checker<'a> {
+    arena: &'a AstArena,
+    scopes: Vec<HashMap<String, Symbol>>, // one for each function being checked, innermost last
+    return_types: Vec<Option<String>>,    // the declared return type of each of those functions
+    breeds: HashSet<String>,
+    errors: Vec<StandardError>,
+}
+
+impl<'a> Checker<'a> {
+    fn new(arena: &'a AstArena) -> Self {
+        // breeds can be used as types before the line they are defined on
+        let breeds = arena
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                AstNode::BreedDefinition(breed) => Some(breed.name.clone()),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            arena,
+            scopes: vec![HashMap::new()],
+            return_types: Vec::new(),
+            breeds,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Checks the node and everything inside of it, returning its type if it is known
+    fn infer(&mut self, id: NodeID) -> Option<String> {
+        match self.arena.get(id) {
+            AstNode::Number(_) => Some("number".to_string()),
+            AstNode::Strings(_) => Some("string".to_string()),
+            AstNode::Boolean(_) => Some("boolean".to_string()),
+            AstNode::Null(_) => Some("null".to_string()),
+            AstNode::Interpolation(node) => {
+                self.infer_all(&node.part_nodes);
+
+                Some("string".to_string())
+            }
+            AstNode::List(node) => {
+                self.infer_all(&node.element_nodes);
+
+                Some("list".to_string())
+            }
+            AstNode::Map(node) => {
+                for (key, value) in node.pairs.iter() {
+                    self.infer(*key);
+                    self.infer(*value);
+                }
+
+                Some("map".to_string())
+            }
+            AstNode::VariableAccess(node) => self.lookup_type(&node.name),
+            AstNode::VariableAssign(node) => {
+                let value_type = self.infer(node.value_node);
+                let annotation = self.annotation(&node.type_annotation);
+
+                if let Some(ref annotation) = annotation {
+                    self.expect(
+                        annotation,
+                        &value_type,
+                        &node.span,
+                        &format!("'{}' is declared as", node.name),
+                    );
+                }
+
+                let symbol = Symbol {
+                    inferred: if annotation.is_some() {
+                        None
+                    } else {
+                        value_type.clone()
+                    },
+                    annotation,
+                    signature: self.signature(node.value_node),
+                };
+                self.declare(&node.name, symbol);
+
+                value_type
+            }
+            AstNode::VariableReassign(node) => {
+                let mut value_type = self.infer(node.value_node);
+
+                if let Some(ref operator) = node.operator {
+                    let current_type = self.lookup_type(&node.name);
+                    value_type = operation_type(operator, &current_type, &value_type);
+                }
+
+                self.reassign(&node.name, &value_type, &node.span);
+
+                value_type
+            }
+            AstNode::ConstAssign(node) => {
+                let value_type = self.infer(node.value_node);
+
+                // constants can't change, so their type holds everywhere just like an annotation
+                let symbol = Symbol {
+                    annotation: value_type.clone(),
+                    inferred: None,
+                    signature: self.signature(node.value_node),
+                };
+                self.declare(&node.name, symbol);
+
+                value_type
+            }
+            AstNode::Destructure(node) => {
+                let value_type = self.infer(node.value_node);
+                self.declare_pattern(&node.pattern);
+
+                value_type
+            }
+            AstNode::Index(node) => {
+                self.infer(node.node_to_index);
+                self.infer(node.index_node);
+
+                None
+            }
+            AstNode::IndexAssign(node) => {
+                self.infer(node.node_to_index);
+                self.infer(node.index_node);
+
+                let value_type = self.infer(node.value_node);
+                value_type.filter(|_| node.operator.is_none())
+            }
+            AstNode::Slice(node) => {
+                let sliced_type = self.infer(node.node_to_slice);
+
+                for bound in [node.start_node, node.end_node, node.step_node]
+                    .into_iter()
+                    .flatten()
+                {
+                    self.infer(bound);
+                }
+
+                sliced_type.filter(|sliced_type| sliced_type == "list" || sliced_type == "string")
+            }
+            AstNode::MemberAccess(node) => {
+                self.infer(node.node_to_access);
+
+                None
+            }
+            AstNode::MemberAssign(node) => {
+                self.infer(node.node_to_access);
+
+                let value_type = self.infer(node.value_node);
+                value_type.filter(|_| node.operator.is_none())
+            }
+            AstNode::BinaryOperator(node) => {
+                let left_type = self.infer(node.left_node);
+                let right_type = self.infer(node.right_node);
+
+                operation_type(&node.operator, &left_type, &right_type)
+            }
+            AstNode::UnaryOperator(node) => {
+                let value_type = self.infer(node.node);
+
+                match node.operator.as_str() {
+                    "not" => Some("boolean".to_string()),
+                    _ => value_type.filter(|value_type| value_type == "number"),
+                }
+            }
+            AstNode::If(node) => {
+                for (condition, body, _) in node.cases.iter() {
+                    self.infer(*condition);
+                    self.infer(*body);
+                }
+
+                if let Some((body, _)) = node.else_case {
+                    self.infer(body);
+                }
+
+                None
+            }
+            AstNode::Match(node) => {
+                self.infer(node.value_node);
+
+                for arm in node.arms.iter() {
+                    self.declare_pattern(&arm.pattern);
+
+                    if let Some(guard) = arm.guard_node {
+                        self.infer(guard);
+                    }
+
+                    self.infer(arm.body_node);
+                }
+
+                None
+            }
+            AstNode::For(node) => {
+                self.infer(node.start_value_node);
+                self.infer(node.end_value_node);
+
+                if let Some(step) = node.step_value_node {
+                    self.infer(step);
+                }
+
+                self.declare(
+                    &node.iterator_name,
+                    Symbol::of_type(Some("number".to_string())),
+                );
+                self.infer_loop(&[node.body_node]);
+
+                None
+            }
+            AstNode::ForEach(node) => {
+                self.infer(node.iterator_node);
+                self.declare_pattern(&node.iterator_pattern);
+                self.infer_loop(&[node.body_node]);
+
+                None
+            }
+            AstNode::While(node) => {
+                self.infer_loop(&[node.condition_node, node.body_node]);
+
+                None
+            }
+            AstNode::TryExcept(node) => {
+                self.infer(node.try_body_node);
+
+                for clause in node.catch_clauses.iter() {
+                    self.declare(
+                        &clause.passed_error,
+                        Symbol::of_type(Some("error".to_string())),
+                    );
+                    self.infer(clause.body_node);
+                }
+
+                if let Some(finally_body) = node.finally_body_node {
+                    self.infer(finally_body);
+                }
+
+                None
+            }
+            AstNode::FunctionDefinition(node) => {
+                if let Some(ref name) = node.name {
+                    let symbol = Symbol {
+                        inferred: Some("function".to_string()),
+                        signature: self.signature(id),
+                        ..Default::default()
+                    };
+                    self.declare(name, symbol);
+                }
+
+                self.check_function(node);
+
+                Some("function".to_string())
+            }
+            AstNode::BreedDefinition(node) => {
+                self.declare(&node.name, Symbol::of_type(Some("breed".to_string())));
+
+                // methods belong to the breed, so they aren't declared like functions are
+                for method in node.method_nodes.iter() {
+                    if let AstNode::FunctionDefinition(method) = self.arena.get(*method) {
+                        self.check_function(method);
+                    }
+                }
+
+                Some("breed".to_string())
+            }
+            AstNode::Call(_) => self.check_call(id),
+            AstNode::Return(node) => {
+                let value_type = match node.node_to_return {
+                    Some(value) => self.infer(value),
+                    None => Some("null".to_string()),
+                };
+
+                if let Some(Some(return_type)) = self.return_types.last().cloned() {
+                    self.expect(
+                        &return_type,
+                        &value_type,
+                        &node.span,
+                        "the function is declared to give",
+                    );
+                }
+
+                None
+            }
+            AstNode::Yield(node) => {
+                if let Some(value) = node.node_to_yield {
+                    self.infer(value);
+                }
+
+                None
+            }
+            AstNode::Import(node) => {
+                self.infer(node.node_to_import);
+
+                if let Some(ref alias) = node.alias {
+                    self.declare(&alias.value, Symbol::of_type(Some("module".to_string())));
+                }
+
+                // the types of names taken from a module aren't known without reading it
+                for name in node.names.iter() {
+                    self.declare(&name.value, Symbol::default());
+                }
+
+                Some("module".to_string())
+            }
+            AstNode::Break(_) | AstNode::Continue(_) => None,
+        }
+    }
+
+    fn infer_all(&mut self, ids: &[NodeID]) {
+        for id in ids {
+            self.infer(*id);
+        }
+    }
+
+    /// Checks the nodes of a loop, which can run again after the loop changes its objects
+    ///
+    /// The loop is checked once without reporting anything to find the objects it changes, and
+    /// those objects have an unknown type while the loop is checked for real
+    fn infer_loop(&mut self, ids: &[NodeID]) {
+        let scopes = self.scopes.clone();
+        let error_count = self.errors.len();
+
+        self.infer_all(ids);
+        self.errors.truncate(error_count);
+
+        let changed_scopes = std::mem::replace(&mut self.scopes, scopes);
+
+        for (scope, changed_scope) in self.scopes.iter_mut().zip(changed_scopes.iter()) {
+            for (name, symbol) in scope.iter_mut() {
+                if changed_scope.get(name) != Some(symbol) {
+                    symbol.inferred = None;
+                    symbol.signature = None;
+                }
+            }
+        }
+
+        self.infer_all(ids);
+    }
+
+    fn check_function(&mut self, node: &FunctionDefinitionNode) {
+        let mut scope = HashMap::new();
+
+        for ((name, type_token), default) in node
+            .argument_names
+            .iter()
+            .zip(node.argument_types.iter())
+            .zip(node.default_nodes.iter())
+        {
+            let annotation = self.annotation(type_token);
+            let default_type = default.and_then(|default| self.infer(default));
+
+            if let (Some(annotation), Some(default)) = (&annotation, default) {
+                self.expect(
+                    annotation,
+                    &default_type,
+                    &self.arena.span(*default),
+                    &format!("argument '{}' is declared as", name.value),
+                );
+            }
+
+            scope.insert(
+                name.value.clone(),
+                Symbol {
+                    annotation,
+                    ..Default::default()
+                },
+            );
+        }
+
+        if let Some(ref rest_name) = node.rest_argument_name {
+            scope.insert(
+                rest_name.value.clone(),
+                Symbol::of_type(Some("list".to_string())),
+            );
+        }
+
+        let return_type = self.annotation(&node.return_type);
+
+        // a generator gives back an iterator, so its 'give' doesn't decide the type of a call
+        self.scopes.push(scope);
+        self.return_types.push(if node.is_generator {
+            None
+        } else {
+            return_type.clone()
+        });
+
+        let body_type = self.infer(node.body_node);
+
+        if node.should_auto_return
+            && !node.is_generator
+            && let Some(ref return_type) = return_type
+        {
+            self.expect(
+                return_type,
+                &body_type,
+                &self.arena.span(node.body_node),
+                "the function is declared to give",
+            );
+        }
+
+        self.return_types.pop();
+        self.scopes.pop();
+    }
+
+    fn check_call(&mut self, id: NodeID) -> Option<String> {
+        let AstNode::Call(node) = self.arena.get(id) else {
+            return None;
+        };
+
+        let callee_type = self.infer(node.node_to_call);
+        let argument_types: Vec<Option<String>> = node
+            .arg_nodes
+            .iter()
+            .map(|argument| self.infer(*argument))
+            .collect();
+        let keyword_types: Vec<Option<String>> = node
+            .keyword_arg_nodes
+            .iter()
+            .map(|(_, argument)| self.infer(*argument))
+            .collect();
+
+        let AstNode::VariableAccess(callee) = self.arena.get(node.node_to_call) else {
+            return None;
+        };
+
+        if let Some(symbol) = self.lookup(&callee.name) {
+            let Some(signature) = symbol.signature else {
+                // calling a breed makes an instance, whose type is the name of the breed
+                return (callee_type.as_deref() == Some("breed")
+                    && self.breeds.contains(&callee.name))
+                .then(|| callee.name.clone());
+            };
+
+            for (index, argument_type) in argument_types.iter().enumerate() {
+                if let Some((name, Some(expected))) = signature.arguments.get(index) {
+                    self.expect(
+                        expected,
+                        argument_type,
+                        &self.arena.span(node.arg_nodes[index]),
+                        &format!("argument '{name}' of '{}' is declared as", callee.name),
+                    );
+                }
+            }
+
+            for ((name_token, argument), argument_type) in
+                node.keyword_arg_nodes.iter().zip(keyword_types.iter())
+            {
+                let expected = signature
+                    .arguments
+                    .iter()
+                    .find(|(name, _)| *name == name_token.value)
+                    .and_then(|(_, expected)| expected.clone());
+
+                if let Some(expected) = expected {
+                    self.expect(
+                        &expected,
+                        argument_type,
+                        &self.arena.span(*argument),
+                        &format!(
+                            "argument '{}' of '{}' is declared as",
+                            name_token.value, callee.name
+                        ),
+                    );
+                }
+            }
+
+            return signature.return_type;
+        }
+
+        let return_type = match callee.name.as_str() {
+            "tostring" | "type" | "chew" => "string",
+            "tonumber" | "length" | "round" => "number",
+            "split" => "list",
+            "set" => "set",
+            "iter" | "range" | "lines" => "iterator",
+            _ => return None,
+        };
+
+        Some(return_type.to_string())
+    }
+
+    /// Returns the signature of a function definition, so that calls to it can be checked
+    fn signature(&mut self, id: NodeID) -> Option<Signature> {
+        let AstNode::FunctionDefinition(node) = self.arena.get(id) else {
+            return None;
+        };
+
+        let return_type = if node.is_generator {
+            Some("iterator".to_string())
+        } else {
+            node.return_type.as_ref().map(|token| token.value.clone())
+        };
+
+        Some(Signature {
+            arguments: node
+                .argument_names
+                .iter()
+                .zip(node.argument_types.iter())
+                .map(|(name, type_token)| {
+                    (
+                        name.value.clone(),
+                        type_token.as_ref().map(|token| token.value.clone()),
+                    )
+                })
+                .collect(),
+            return_type,
+        })
+    }
+
+    /// Returns the name of the type in an annotation, reporting it if there is no such type
+    fn annotation(&mut self, type_token: &Option<Token>) -> Option<String> {
+        let type_token = type_token.as_ref()?;
+
+        if !TYPE_NAMES.contains(&type_token.value.as_str())
+            && !self.breeds.contains(&type_token.value)
+        {
+            self.errors.push(
+                StandardError::new(
+                    format!("unknown type '{}'", type_token.value).as_str(),
+                    type_token.span.clone(),
+                    Some("use a type like 'number', 'string', 'list' or 'any', or the name of a breed in this file"),
+                )
+                .with_kind("TypeError"),
+            );
+
+            return None;
+        }
+
+        Some(type_token.value.clone())
+    }
+
+    /// Reports a mismatch if the type found is known and isn't the type expected
+    fn expect(&mut self, expected: &str, found: &Option<String>, span: &Span, subject: &str) {
+        let Some(found) = found else {
+            return;
+        };
+
+        if is_compatible(expected, found) {
+            return;
+        }
+
+        self.errors.push(
+            StandardError::new(
+                format!("{subject} '{expected}', but this is a '{found}'").as_str(),
+                span.clone(),
+                None,
+            )
+            .with_kind("TypeError"),
+        );
+    }
+
+    fn declare(&mut self, name: &str, symbol: Symbol) {
+        self.scopes
+            .last_mut()
+            .expect("there is always a scope")
+            .insert(name.to_string(), symbol);
+    }
+
+    fn declare_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Literal(_) => {}
+            Pattern::Binding(name_token) => self.declare(&name_token.value, Symbol::default()),
+            Pattern::Type(type_token, name_token) => self.declare(
+                &name_token.value,
+                Symbol::of_type(Some(type_token.value.clone())),
+            ),
+            Pattern::List(element_patterns, rest_name_token) => {
+                for element_pattern in element_patterns {
+                    self.declare_pattern(element_pattern);
+                }
+
+                if let Some(rest_name_token) = rest_name_token {
+                    self.declare(
+                        &rest_name_token.value,
+                        Symbol::of_type(Some("list".to_string())),
+                    );
+                }
+            }
+        }
+    }
+
+    fn reassign(&mut self, name: &str, value_type: &Option<String>, span: &Span) {
+        let Some(scope) = self
+            .scopes
+            .iter_mut()
+            .rev()
+            .find(|scope| scope.contains_key(name))
+        else {
+            return;
+        };
+        let symbol = scope.get_mut(name).unwrap();
+
+        if let Some(annotation) = symbol.annotation.clone() {
+            self.expect(
+                &annotation,
+                value_type,
+                span,
+                &format!("'{name}' is declared as"),
+            );
+
+            return;
+        }
+
+        if symbol.inferred != *value_type {
+            symbol.inferred = None;
+        }
+
+        symbol.signature = None;
+    }
+
+    fn lookup(&self, name: &str) -> Option<Symbol> {
+        let (innermost, outer) = self.scopes.split_last()?;
+
+        if let Some(symbol) = innermost.get(name) {
+            return Some(symbol.clone());
+        }
+
+        // outer objects can change before a function is called, so only what's certain is kept
+        outer
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .map(|symbol| Symbol {
+                annotation: symbol.annotation.clone(),
+                inferred: None,
+                signature: symbol.signature.clone(),
+            })
+    }
+
+    fn lookup_type(&self, name: &str) -> Option<String> {
+        let symbol = self.lookup(name)?;
+
+        symbol.annotation.or(symbol.inferred)
+    }
+}
+
+/// Returns the type an operator gives for operands of the given types, if it is known
+fn operation_type(operator: &str, left: &Option<String>, right: &Option<String>) -> Option<String> {
+    match operator {
+        "==" | "!=" | "<" | ">" | "<=" | ">=" => Some("boolean".to_string()),
+        "and" | "or" if left == right => left.clone(),
+        "+" | "-" | "*" | "/" | "//" | "^" | "%"
+            if left.as_deref() == Some("number") && right.as_deref() == Some("number") =>
+        {
+            Some("number".to_string())
+        }
+        "+" if left.as_deref() == Some("string") && right.as_deref() == Some("string") => {
+            Some("string".to_string())
+        }
+        _ => None,
+    }
+}
+
+fn is_compatible(expected: &str, found: &str) -> bool {
+    expected == "any" || found == "any" || expected == found
+}
+
+#[test]
+fn test_check() {
+    use crate::parse;
+    use glang_lexer::Lexer;
+    use std::path::Path;
+
+    let code = "
+        obj count: number = 0;
+        count = \"five\";
+        obj flexible = 1;
+        flexible = \"one\";
+        obj name: string = flexible;
+        func add(a: number, b: number): number { give a + b; }
+        add(1, \"two\");
+        obj total: number = add(1, 2);
+    ";
+
+    let mut lexer = Lexer::new(Path::new("<test>"), code);
+    let tokens = lexer.make_tokens().ok().unwrap();
+    let arena = parse(&tokens, code).ok().unwrap();
+
+    let errors = check(&arena);
+
+    assert_eq!(errors.len(), 2); // the reassigned 'count' and the second argument of 'add'
+    assert_eq!(
+        errors[0].text,
+        "'count' is declared as 'number', but this is a 'string'"
+    );
+    assert_eq!(
+        errors[1].text,
+        "argument 'b' of 'add' is declared as 'number', but this is a 'string'"
+    );
+}
+
+#[cfg(test)]
+fn check_code(code: &str) -> Vec<String> {
+    use crate::parse;
+    use glang_lexer::Lexer;
+    use std::path::Path;
+
+    let mut lexer = Lexer::new(Path::new("<test>"), code);
+    let tokens = lexer.make_tokens().ok().unwrap();
+    let arena = parse(&tokens, code).ok().unwrap();
+
+    check(&arena).into_iter().map(|error| error.text).collect()
+}
+
+#[test]
+fn test_check_reports_mismatches() {
+    let errors = check_code(
+        "
+        obj name: nothing = 1;
+        func greet(): string { give 1; }
+        obj scores: list = {};
+        walk i = 0 through 3 {
+            obj label: string = i;
+        }
+        if true {
+            obj flag: boolean = \"yes\";
+        }
+        ",
+    );
+
+    assert_eq!(
+        errors,
+        vec![
+            "unknown type 'nothing'",
+            "the function is declared to give 'string', but this is a 'number'",
+            "'scores' is declared as 'list', but this is a 'map'",
+            "'label' is declared as 'string', but this is a 'number'",
+            "'flag' is declared as 'boolean', but this is a 'string'",
+        ]
+    );
+}
+
+#[test]
+fn test_check_accepts_valid_programs() {
+    let errors = check_code(
+        "
+        obj total: number = 0;
+        walk i = 0 through 10 {
+            total += i;
+        }
+
+        obj value = 0;
+        walk i = 0 through 3 {
+            if i > 0 {
+                obj text: string = value;
+            }
+            value = \"text\";
+        }
+
+        obj count = 0;
+        while count < 3 {
+            obj current: number = count;
+            count++;
+        }
+
+        obj result = 1;
+        if total > 5 {
+            result = \"big\";
+        }
+        obj described: string = result;
+
+        func describe(x: any): string { give tostring(x); }
+        describe([1, 2]);
+        ",
+    );
+
+    assert_eq!(errors, Vec::<String>::new());
+}
+
+#[test]
+fn test_check_loop_keeps_unchanged_types() {
+    // objects the loop doesn't change keep the type they had before it
+    let errors = check_code(
+        "
+        obj limit = 10;
+        walk [a, b] through [[1, 2]] {
+            obj wrong: string = limit;
+        }
+        ",
+    );
+
+    assert_eq!(
+        errors,
+        vec!["'wrong' is declared as 'string', but this is a 'number'"]
+    );
+}
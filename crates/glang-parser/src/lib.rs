@@ -1,12 +1,16 @@
 mod ast_node;
+mod checker;
 mod parse_result;
 mod parser;
 
 pub use ast_node::{
-    AstArena, AstNode, BinaryOperatorNode, BreakNode, CallNode, ConstAssignNode, ContinueNode,
-    ForEachNode, ForNode, FunctionDefinitionNode, IfNode, ImportNode, ListNode, NodeID, NumberNode,
-    ReturnNode, StringNode, TryExceptNode, UnaryOperatorNode, VariableAccessNode,
-    VariableAssignNode, VariableRessignNode, WhileNode,
+    AstArena, AstNode, BinaryOperatorNode, BooleanNode, BreakNode, BreedDefinitionNode, CallNode,
+    CatchClause, ConstAssignNode, ContinueNode, DestructureNode, ForEachNode, ForNode,
+    FunctionDefinitionNode, IfNode, ImportNode, IndexAssignNode, IndexNode, InterpolationNode,
+    ListNode, MapNode, MatchArm, MatchNode, MemberAccessNode, MemberAssignNode, NodeID, NullNode,
+    NumberNode, Pattern, ReturnNode, SliceNode, StringNode, TryExceptNode, UnaryOperatorNode,
+    VariableAccessNode, VariableAssignNode, VariableRessignNode, WhileNode, YieldNode,
 };
+pub use checker::check;
 pub use parse_result::ParseResult;
 pub use parser::{Parser, parse};
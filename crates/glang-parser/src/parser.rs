@@ -1,6 +1,6 @@
 use crate::{
     ParseResult,
-    ast_node::{AstArena, NodeID},
+    ast_node::{AstArena, AstNode, CatchClause, MatchArm, NodeID, Pattern},
 };
 use glang_attributes::{Position, Span, StandardError};
 use glang_lexer::{Token, TokenType};
@@ -23,6 +23,20 @@ pub fn parse(tokens: &[Token], contents: &str) -> Result<AstArena, StandardError
     Ok(parser.arena)
 }
 
+/// Returns the binary operator a compound assignment token applies, like '+' for '+='
+fn compound_operator(token_type: &TokenType) -> Option<&'static str> {
+    match token_type {
+        TokenType::TT_PLUSEQ | TokenType::TT_INCREMENT => Some("+"),
+        TokenType::TT_MINUSEQ | TokenType::TT_DECREMENT => Some("-"),
+        TokenType::TT_MULEQ => Some("*"),
+        TokenType::TT_DIVEQ => Some("/"),
+        TokenType::TT_FLOORDIVEQ => Some("//"),
+        TokenType::TT_POWEQ => Some("^"),
+        TokenType::TT_MODEQ => Some("%"),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 enum Operator {
     ComparisonExpr,
@@ -38,6 +52,7 @@ pub struct Parser {
     pub current_token: Option<Token>,
     pub arena: AstArena,
     contents: String,
+    function_tosses: Vec<bool>, // whether each function being parsed contains a 'toss', innermost last
 }
 
 impl Parser {
@@ -48,6 +63,7 @@ impl Parser {
             current_token: None,
             arena: AstArena::new(),
             contents: contents.to_owned(),
+            function_tosses: Vec::new(),
         };
         parser.advance();
 
@@ -121,6 +137,10 @@ impl Parser {
         self.current_token.as_ref().unwrap().span.end.clone()
     }
 
+    fn previous_position_end(&self) -> Position {
+        self.tokens[self.token_index as usize - 1].span.end.clone()
+    }
+
     fn comparison_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
@@ -247,6 +267,79 @@ impl Parser {
         ))
     }
 
+    fn map_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let mut pairs: Vec<(NodeID, NodeID)> = Vec::new();
+        let pos_start = self.current_position_start();
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(StandardError::new(
+                "expected map initializing bracket",
+                self.current_span(),
+                Some("add a '{' to start the map"),
+            ));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        while self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            let key = parse_result.register(self.expr());
+
+            if parse_result.error.is_some() {
+                return parse_result.failure(StandardError::new(
+                    "expected closing bracket or map key",
+                    self.current_span(),
+                    Some("add a '}' to close the map or add a 'key: value' pair"),
+                ));
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_COLON {
+                return parse_result.failure(StandardError::new(
+                    "expected ':'",
+                    self.current_span(),
+                    Some("add a ':' between the key and the value like '\"key\": value'"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let value = parse_result.register(self.expr());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            pairs.push((key, value));
+
+            if self.current_token_ref().token_type != TokenType::TT_COMMA {
+                break;
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+        }
+
+        if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            return parse_result.failure(StandardError::new(
+                "expected '}' or next map entry",
+                self.current_span(),
+                Some("add a '}' to close the map or add a 'key: value' pair followed by a comma"),
+            ));
+        }
+
+        let pos_end = self.current_position_end();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.map_node(
+            pairs,
+            Span::new(&self.current_span().filename, pos_start, pos_end),
+        ))
+    }
+
     fn if_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
         let (if_parse_result, cases, else_case) = self.if_expr_cases("if");
@@ -475,6 +568,252 @@ impl Parser {
         (parse_result, cases, else_case)
     }
 
+    fn match_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let pos_start = self.current_position_start();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let value = parse_result.register(self.expr());
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(StandardError::new(
+                "expected '{'",
+                self.current_span(),
+                Some("add a '{' to start the arms of the 'sniff'"),
+            ));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let mut arms: Vec<MatchArm> = Vec::new();
+
+        while self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+            let (pattern_result, pattern) = self.match_pattern();
+            parse_result.register(pattern_result);
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            let mut guard_node: Option<NodeID> = None;
+
+            if self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "if")
+            {
+                parse_result.register_advancement();
+                self.advance();
+
+                let guard = parse_result.register(self.expr());
+
+                if parse_result.error.is_some() {
+                    return parse_result;
+                }
+
+                guard_node = Some(guard);
+            }
+
+            let should_return_null = if self.current_token_ref().token_type == TokenType::TT_ARROW {
+                false
+            } else if self.current_token_ref().token_type == TokenType::TT_LBRACKET {
+                true
+            } else {
+                return parse_result.failure(StandardError::new(
+                    "expected '->' or '{'",
+                    self.current_span(),
+                    Some("add a '->' followed by a value, or a '{' to define the body of the arm"),
+                ));
+            };
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let body = if should_return_null {
+                parse_result.register(self.statements())
+            } else {
+                parse_result.register(self.expr())
+            };
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            if should_return_null {
+                if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+                    return parse_result.failure(StandardError::new(
+                        "expected '}'",
+                        self.current_span(),
+                        Some("add a '}' to close the body"),
+                    ));
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+            }
+
+            arms.push(MatchArm {
+                pattern: pattern.unwrap(),
+                guard_node,
+                body_node: body,
+                should_return_null,
+            });
+
+            if self.current_token_ref().token_type == TokenType::TT_COMMA {
+                parse_result.register_advancement();
+                self.advance();
+            } else if !should_return_null
+                && self.current_token_ref().token_type != TokenType::TT_RBRACKET
+            {
+                return parse_result.failure(StandardError::new(
+                    "expected ',' or '}'",
+                    self.current_span(),
+                    Some("add a ',' between the arms of the 'sniff' or close it with '}'"),
+                ));
+            }
+        }
+
+        let pos_end = self.current_position_end();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.match_node(
+            value,
+            arms,
+            Span::new(&self.current_span().filename, pos_start, pos_end),
+        ))
+    }
+
+    fn match_pattern(&mut self) -> (ParseResult, Option<Pattern>) {
+        let mut parse_result = ParseResult::new();
+        let token = self.current_token_copy();
+
+        if token.token_type == TokenType::TT_LSQUARE {
+            parse_result.register_advancement();
+            self.advance();
+
+            let mut element_patterns: Vec<Pattern> = Vec::new();
+            let mut rest_name_token: Option<Token> = None;
+
+            while self.current_token_ref().token_type != TokenType::TT_RSQUARE {
+                if rest_name_token.is_some() {
+                    return (
+                        parse_result.failure(StandardError::new(
+                            "expected ']'",
+                            self.current_span(),
+                            Some("the element starting with '...' must be the last element"),
+                        )),
+                        None,
+                    );
+                }
+
+                if self.current_token_ref().token_type == TokenType::TT_ELLIPSIS {
+                    parse_result.register_advancement();
+                    self.advance();
+
+                    if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                        return (
+                            parse_result.failure(StandardError::new(
+                                "expected identifier",
+                                self.current_span(),
+                                Some("add a name for the rest of the list like '...rest'"),
+                            )),
+                            None,
+                        );
+                    }
+
+                    rest_name_token = Some(self.current_token_copy());
+
+                    parse_result.register_advancement();
+                    self.advance();
+                } else {
+                    let (element_result, element_pattern) = self.match_pattern();
+                    parse_result.register(element_result);
+
+                    if parse_result.error.is_some() {
+                        return (parse_result, None);
+                    }
+
+                    element_patterns.push(element_pattern.unwrap());
+                }
+
+                if self.current_token_ref().token_type == TokenType::TT_COMMA {
+                    parse_result.register_advancement();
+                    self.advance();
+                } else if self.current_token_ref().token_type != TokenType::TT_RSQUARE {
+                    return (
+                        parse_result.failure(StandardError::new(
+                            "expected ',' or ']'",
+                            self.current_span(),
+                            Some("add a ',' between the elements of the pattern or close it with ']'"),
+                        )),
+                        None,
+                    );
+                }
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            return (
+                parse_result,
+                Some(Pattern::List(element_patterns, rest_name_token)),
+            );
+        }
+
+        if token.token_type == TokenType::TT_IDENTIFIER {
+            parse_result.register_advancement();
+            self.advance();
+
+            // a second name means the first one is a type, like 'number n'
+            if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER {
+                let name_token = self.current_token_copy();
+
+                parse_result.register_advancement();
+                self.advance();
+
+                return (parse_result, Some(Pattern::Type(token, name_token)));
+            }
+
+            return (parse_result, Some(Pattern::Binding(token)));
+        }
+
+        if matches!(
+            token.token_type,
+            TokenType::TT_NUM
+                | TokenType::TT_STR
+                | TokenType::TT_INTERPOLATION_START
+                | TokenType::TT_MINUS
+        ) || token.matches(TokenType::TT_KEYWORD, "true")
+            || token.matches(TokenType::TT_KEYWORD, "false")
+            || token.matches(TokenType::TT_KEYWORD, "null")
+        {
+            let literal = parse_result.register(self.factor());
+
+            if parse_result.error.is_some() {
+                return (parse_result, None);
+            }
+
+            return (parse_result, Some(Pattern::Literal(literal)));
+        }
+
+        (
+            parse_result.failure(StandardError::new(
+                "expected pattern",
+                self.current_span(),
+                Some("add a value like '0', a name like 'x', a type like 'number n', a list like '[first, ...rest]' or '_'"),
+            )),
+            None,
+        )
+    }
+
     fn for_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
@@ -492,6 +831,31 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        // a list of names unpacks each value, like 'walk [key, value] through pairs'
+        if self.current_token_ref().token_type == TokenType::TT_LSQUARE {
+            let pos_start = self.current_position_start();
+            let (pattern_result, pattern) = self.match_pattern();
+            parse_result.register(pattern_result);
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            let span = Span::new(
+                &self.current_span().filename,
+                pos_start,
+                self.previous_position_end(),
+            );
+
+            let for_each = parse_result.register(self.for_each_expr(pattern.unwrap(), span));
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            return parse_result.success(for_each);
+        }
+
         if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
             return parse_result.failure(StandardError::new(
                 "expected identifier",
@@ -603,44 +967,15 @@ impl Parser {
             .current_token_ref()
             .matches(TokenType::TT_KEYWORD, "through")
         {
-            parse_result.register_advancement();
-            self.advance();
-
-            let iterator = parse_result.register(self.expr());
-
-            if parse_result.error.is_some() {
-                return parse_result;
-            }
-
-            if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
-                return parse_result.failure(StandardError::new(
-                    "expected '{'",
-                    self.current_span(),
-                    Some("add a '{' to define the body"),
-                ));
-            }
-
-            parse_result.register_advancement();
-            self.advance();
-
-            let body = parse_result.register(self.statements());
+            let span = var_name.span.clone();
+            let for_each =
+                parse_result.register(self.for_each_expr(Pattern::Binding(var_name), span));
 
             if parse_result.error.is_some() {
                 return parse_result;
             }
 
-            if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
-                return parse_result.failure(StandardError::new(
-                    "expected '}'",
-                    self.current_span(),
-                    Some("add a '}' to close the body"),
-                ));
-            }
-
-            parse_result.register_advancement();
-            self.advance();
-
-            parse_result.success(self.arena.for_each_node(var_name, iterator, body))
+            parse_result.success(for_each)
         } else {
             return parse_result.failure(StandardError::new(
                 "expected '=' or 'through'",
@@ -650,24 +985,25 @@ impl Parser {
         }
     }
 
-    fn while_expr(&mut self) -> ParseResult {
+    /// Parses the 'through' part of a walk loop, after the name or names of each value
+    fn for_each_expr(&mut self, iterator_pattern: Pattern, span: Span) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
         if !self
             .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "while")
+            .matches(TokenType::TT_KEYWORD, "through")
         {
             return parse_result.failure(StandardError::new(
-                "expected keyword",
+                "expected 'through'",
                 self.current_span(),
-                Some("add the 'while' keyword to initialize a while loop"),
+                Some("add the 'through' keyword followed by the values to walk through"),
             ));
         }
 
         parse_result.register_advancement();
         self.advance();
 
-        let condition = parse_result.register(self.expr());
+        let iterator = parse_result.register(self.expr());
 
         if parse_result.error.is_some() {
             return parse_result;
@@ -701,26 +1037,35 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        parse_result.success(self.arena.while_node(condition, body))
+        parse_result.success(
+            self.arena
+                .for_each_node(iterator_pattern, iterator, body, span),
+        )
     }
 
-    fn try_expr(&mut self) -> ParseResult {
+    fn while_expr(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
 
         if !self
             .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "try")
+            .matches(TokenType::TT_KEYWORD, "while")
         {
             return parse_result.failure(StandardError::new(
                 "expected keyword",
                 self.current_span(),
-                Some("add the 'try' keyword to isolate unsafe code"),
+                Some("add the 'while' keyword to initialize a while loop"),
             ));
         }
 
         parse_result.register_advancement();
         self.advance();
 
+        let condition = parse_result.register(self.expr());
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
         if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
             return parse_result.failure(StandardError::new(
                 "expected '{'",
@@ -732,7 +1077,7 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        let try_body = parse_result.register(self.statements());
+        let body = parse_result.register(self.statements());
 
         if parse_result.error.is_some() {
             return parse_result;
@@ -749,30 +1094,23 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        parse_result.success(self.arena.while_node(condition, body))
+    }
+
+    fn try_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
         if !self
             .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "catch")
+            .matches(TokenType::TT_KEYWORD, "try")
         {
             return parse_result.failure(StandardError::new(
                 "expected keyword",
                 self.current_span(),
-                Some("add the 'catch' keyword to isolate the safe code to execute if the 'try' block fails"),
-            ));
-        }
-
-        parse_result.register_advancement();
-        self.advance();
-
-        if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
-            return parse_result.failure(StandardError::new(
-                "expected identifier",
-                self.current_span(),
-                Some("add a name for caught error like 'error'"),
+                Some("add the 'try' keyword to isolate unsafe code"),
             ));
         }
 
-        let error_name_token = self.current_token_copy();
-
         parse_result.register_advancement();
         self.advance();
 
@@ -787,7 +1125,7 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
-        let except_body = parse_result.register(self.statements());
+        let try_body = parse_result.register(self.statements());
 
         if parse_result.error.is_some() {
             return parse_result;
@@ -804,9 +1142,122 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        let mut catch_clauses: Vec<CatchClause> = Vec::new();
+
+        while self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "catch")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(StandardError::new(
+                    "expected identifier",
+                    self.current_span(),
+                    Some("add a name for caught error like 'error'"),
+                ));
+            }
+
+            let mut error_name_token = self.current_token_copy();
+            let mut kind: Option<String> = None;
+
+            parse_result.register_advancement();
+            self.advance();
+
+            // two names like 'catch ZeroDivisionError error' only catch errors of that kind
+            if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER {
+                kind = Some(error_name_token.value);
+                error_name_token = self.current_token_copy();
+
+                parse_result.register_advancement();
+                self.advance();
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected '{'",
+                    self.current_span(),
+                    Some("add a '{' to define the body"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let catch_body = parse_result.register(self.statements());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected '}'",
+                    self.current_span(),
+                    Some("add a '}' to close the body"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            catch_clauses.push(CatchClause {
+                kind,
+                passed_error: error_name_token.value,
+                body_node: catch_body,
+            });
+        }
+
+        let mut finally_body: Option<NodeID> = None;
+
+        if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "finally")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected '{'",
+                    self.current_span(),
+                    Some("add a '{' to define the body"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            finally_body = Some(parse_result.register(self.statements()));
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected '}'",
+                    self.current_span(),
+                    Some("add a '}' to close the body"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+        }
+
+        if catch_clauses.is_empty() && finally_body.is_none() {
+            return parse_result.failure(StandardError::new(
+                "expected keyword",
+                self.current_span(),
+                Some("add the 'catch' keyword to isolate the safe code to execute if the 'try' block fails"),
+            ));
+        }
+
         parse_result.success(
             self.arena
-                .try_except_node(try_body, except_body, error_name_token),
+                .try_except_node(try_body, catch_clauses, finally_body),
         )
     }
 
@@ -827,16 +1278,87 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        let mut names = Vec::new();
+        let next_tok = self.next_token_copy();
+
+        // the names come before 'from', so they can only be told apart from the module by
+        // what follows the first one
+        if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER
+            && next_tok.is_some_and(|tok| {
+                tok.token_type == TokenType::TT_COMMA || tok.matches(TokenType::TT_KEYWORD, "from")
+            })
+        {
+            loop {
+                if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                    return parse_result.failure(StandardError::new(
+                        "expected identifier",
+                        self.current_span(),
+                        Some("add the name of an object to take from the module"),
+                    ));
+                }
+
+                names.push(self.current_token_copy());
+
+                parse_result.register_advancement();
+                self.advance();
+
+                if self.current_token_ref().token_type != TokenType::TT_COMMA {
+                    break;
+                }
+
+                parse_result.register_advancement();
+                self.advance();
+            }
+
+            if !self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "from")
+            {
+                return parse_result.failure(StandardError::new(
+                    "expected keyword",
+                    self.current_span(),
+                    Some("add the 'from' keyword before the module to take the names from"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+        }
+
         let import = parse_result.register(self.expr());
 
         if parse_result.error.is_some() {
             return parse_result;
         }
 
+        let mut alias = None;
+
+        if names.is_empty()
+            && self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "as")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(StandardError::new(
+                    "expected identifier",
+                    self.current_span(),
+                    Some("add a name for the module like 'math'"),
+                ));
+            }
+
+            alias = Some(self.current_token_copy());
+
+            parse_result.register_advancement();
+            self.advance();
+        }
+
         parse_result.register_advancement();
         self.advance();
 
-        parse_result.success(self.arena.import_node(import))
+        parse_result.success(self.arena.import_node(import, alias, names))
     }
 
     fn expr(&mut self) -> ParseResult {
@@ -853,6 +1375,10 @@ impl Parser {
             parse_result.register_advancement();
             self.advance();
 
+            if self.current_token_ref().token_type == TokenType::TT_LSQUARE {
+                return self.destructure_expr(parse_result);
+            }
+
             if self.current_token_copy().token_type != TokenType::TT_IDENTIFIER {
                 return parse_result.failure(StandardError::new(
                     "expected identifier",
@@ -866,6 +1392,13 @@ impl Parser {
             parse_result.register_advancement();
             self.advance();
 
+            let (type_result, type_token) = self.type_annotation();
+            parse_result.register(type_result);
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
             if self.current_token_copy().token_type != TokenType::TT_EQ {
                 return parse_result.failure(StandardError::new(
                     "expected '='",
@@ -889,26 +1422,74 @@ impl Parser {
                 return parse_result;
             }
 
-            return parse_result.success(self.arena.variable_assign_node(var_name, expr));
+            return parse_result
+                .success(self.arena.variable_assign_node(var_name, type_token, expr));
         } else if self.current_token_copy().token_type == TokenType::TT_IDENTIFIER
-            && next_tok.token_type == TokenType::TT_EQ
+            && (next_tok.token_type == TokenType::TT_EQ
+                || compound_operator(&next_tok.token_type).is_some())
         {
             let var_name = self.current_token_copy();
 
             parse_result.register_advancement();
             self.advance();
 
-            // advance past the '=' token too
+            let operator = compound_operator(&self.current_token_ref().token_type);
+            let expr = parse_result.register(self.assignment_value());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            return parse_result.success(
+                self.arena
+                    .variable_reassign_node(var_name, expr, operator, false),
+            );
+        } else if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "nonlocal")
+        {
             parse_result.register_advancement();
             self.advance();
 
-            let expr = parse_result.register(self.expr());
+            if self.current_token_copy().token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(StandardError::new(
+                    "expected identifier",
+                    self.current_span(),
+                    Some("add the name of a variable from an enclosing scope"),
+                ));
+            }
+
+            let var_name = self.current_token_copy();
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let operator = compound_operator(&self.current_token_ref().token_type);
+
+            if self.current_token_copy().token_type != TokenType::TT_EQ && operator.is_none() {
+                return parse_result.failure(StandardError::new(
+                    "expected '='",
+                    self.current_span(),
+                    Some(
+                        format!(
+                            "add an '=' to set the value of the variable '{}'",
+                            &var_name.value
+                        )
+                        .as_str(),
+                    ),
+                ));
+            }
+
+            let expr = parse_result.register(self.assignment_value());
 
             if parse_result.error.is_some() {
                 return parse_result;
             }
 
-            return parse_result.success(self.arena.variable_reassign_node(var_name, expr));
+            return parse_result.success(
+                self.arena
+                    .variable_reassign_node(var_name, expr, operator, true),
+            );
         } else if self
             .current_token_ref()
             .matches(TokenType::TT_KEYWORD, "stay")
@@ -972,27 +1553,150 @@ impl Parser {
             ));
         }
 
-        parse_result.success(node)
-    }
+        let operator = compound_operator(&self.current_token_ref().token_type);
+        let is_assignment =
+            self.current_token_ref().token_type == TokenType::TT_EQ || operator.is_some();
 
-    fn statement(&mut self) -> ParseResult {
-        let mut parse_result = ParseResult::new();
-        let pos_start = self.current_position_start();
+        if is_assignment && let AstNode::Index(target) = self.arena.get(node).clone() {
+            let expr = parse_result.register(self.assignment_value());
 
-        if self
-            .current_token_ref()
-            .matches(TokenType::TT_KEYWORD, "give")
-        {
-            parse_result.register_advancement();
-            self.advance();
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
 
-            let expr = parse_result.try_register(self.expr());
+            return parse_result.success(self.arena.index_assign_node(target, expr, operator));
+        }
 
-            if expr.is_none() {
-                self.reverse(parse_result.to_reverse_count);
+        if is_assignment && let AstNode::MemberAccess(target) = self.arena.get(node).clone() {
+            let expr = parse_result.register(self.assignment_value());
+
+            if parse_result.error.is_some() {
+                return parse_result;
             }
 
-            return parse_result.success(self.arena.return_node(
+            return parse_result.success(self.arena.member_assign_node(target, expr, operator));
+        }
+
+        parse_result.success(node)
+    }
+
+    /// Parses a list of names after 'obj' and the value to unpack into them, like 'obj [a, b] = pair;'
+    fn destructure_expr(&mut self, mut parse_result: ParseResult) -> ParseResult {
+        let pos_start = self.current_position_start();
+        let (pattern_result, pattern) = self.match_pattern();
+        parse_result.register(pattern_result);
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
+        let span = Span::new(
+            &self.current_span().filename,
+            pos_start,
+            self.previous_position_end(),
+        );
+
+        if self.current_token_ref().token_type != TokenType::TT_EQ {
+            return parse_result.failure(StandardError::new(
+                "expected '='",
+                self.current_span(),
+                Some("add an '=' to set the list to unpack"),
+            ));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let expr = parse_result.register(self.expr());
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
+        parse_result.success(self.arena.destructure_node(pattern.unwrap(), expr, span))
+    }
+
+    /// Parses the value after an '=' or a compound assignment operator like '+='
+    ///
+    /// '++' and '--' have no value after them, so they are given a value of 1
+    fn assignment_value(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let operator_token = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if matches!(
+            operator_token.token_type,
+            TokenType::TT_INCREMENT | TokenType::TT_DECREMENT
+        ) {
+            return parse_result.success(self.arena.number_node(Token::new(
+                TokenType::TT_NUM,
+                Some("1".to_string()),
+                operator_token.span,
+            )));
+        }
+
+        let expr = parse_result.register(self.expr());
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
+        parse_result.success(expr)
+    }
+
+    fn statement(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let pos_start = self.current_position_start();
+
+        if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "give")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            let expr = parse_result.try_register(self.expr());
+
+            if expr.is_none() {
+                self.reverse(parse_result.to_reverse_count);
+            }
+
+            return parse_result.success(self.arena.return_node(
+                expr,
+                Span::new(
+                    &self.current_span().filename,
+                    pos_start,
+                    self.current_position_end(),
+                ),
+            ));
+        } else if self
+            .current_token_ref()
+            .matches(TokenType::TT_KEYWORD, "toss")
+        {
+            // a 'toss' turns the function it is in into a generator
+            match self.function_tosses.last_mut() {
+                Some(tosses) => *tosses = true,
+                None => {
+                    return parse_result.failure(StandardError::new(
+                        "'toss' outside of a function",
+                        self.current_span(),
+                        Some("'toss' can only be used inside of a function body"),
+                    ));
+                }
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            let expr = parse_result.try_register(self.expr());
+
+            if expr.is_none() {
+                self.reverse(parse_result.to_reverse_count);
+            }
+
+            return parse_result.success(self.arena.yield_node(
                 expr,
                 Span::new(
                     &self.current_span().filename,
@@ -1102,68 +1806,289 @@ impl Parser {
 
     fn call(&mut self) -> ParseResult {
         let mut parse_result = ParseResult::new();
-        let atom = parse_result.register(self.atom());
+        let mut node = parse_result.register(self.atom());
 
         if parse_result.error.is_some() {
             return parse_result;
         }
 
-        if self.current_token_ref().token_type == TokenType::TT_LPAREN {
-            parse_result.register_advancement();
-            self.advance();
+        // calls, indexes and members can be chained like 'get_dogs()[0].name'
+        loop {
+            node = match self.current_token_ref().token_type {
+                TokenType::TT_LPAREN => parse_result.register(self.call_arguments(node)),
+                TokenType::TT_LSQUARE => parse_result.register(self.index_expr(node)),
+                TokenType::TT_DOT => parse_result.register(self.member_expr(node)),
+                _ => break,
+            };
 
-            let mut arg_nodes: Vec<NodeID> = Vec::new();
-            let closing_call: Token;
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+        }
+
+        parse_result.success(node)
+    }
+
+    fn call_arguments(&mut self, node_to_call: NodeID) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
+        parse_result.register_advancement();
+        self.advance();
 
-            if self.current_token_ref().token_type == TokenType::TT_RPAREN {
-                closing_call = self.current_token_copy();
+        let mut arg_nodes: Vec<NodeID> = Vec::new();
+        let mut keyword_arg_nodes: Vec<(Token, NodeID)> = Vec::new();
+
+        while self.current_token_ref().token_type != TokenType::TT_RPAREN {
+            let is_keyword_arg = self.current_token_ref().token_type == TokenType::TT_IDENTIFIER
+                && self
+                    .next_token_copy()
+                    .is_some_and(|token| token.token_type == TokenType::TT_EQ);
+
+            if is_keyword_arg {
+                let arg_name_token = self.current_token_copy();
 
                 parse_result.register_advancement();
                 self.advance();
-            } else {
+                parse_result.register_advancement();
+                self.advance();
+
                 let expr = parse_result.register(self.expr());
 
                 if parse_result.error.is_some() {
+                    return parse_result;
+                }
+
+                keyword_arg_nodes.push((arg_name_token, expr));
+            } else {
+                if !keyword_arg_nodes.is_empty() {
                     return parse_result.failure(StandardError::new(
-                        "expected keyword, object, function, expression",
+                        "expected keyword argument",
                         self.current_span(),
-                        None,
+                        Some("arguments given by name must come after all the other arguments"),
                     ));
                 }
 
+                let is_first_arg = arg_nodes.is_empty();
+                let expr = parse_result.register(self.expr());
+
+                if parse_result.error.is_some() {
+                    if is_first_arg {
+                        return parse_result.failure(StandardError::new(
+                            "expected keyword, object, function, expression",
+                            self.current_span(),
+                            None,
+                        ));
+                    }
+
+                    return parse_result;
+                }
+
                 arg_nodes.push(expr);
+            }
 
-                while self.current_token_ref().token_type == TokenType::TT_COMMA {
-                    parse_result.register_advancement();
-                    self.advance();
+            if self.current_token_ref().token_type == TokenType::TT_COMMA {
+                parse_result.register_advancement();
+                self.advance();
+            } else if self.current_token_ref().token_type != TokenType::TT_RPAREN {
+                return parse_result.failure(StandardError::new(
+                    "expected ',' or ')'",
+                    self.current_span(),
+                    Some("add a ',' to input all the function arguments or close with a ')' to call the function"),
+                ));
+            }
+        }
 
-                    let expr = parse_result.register(self.expr());
+        let closing_call = self.current_token_copy();
 
-                    if parse_result.error.is_some() {
-                        return parse_result;
-                    }
+        parse_result.register_advancement();
+        self.advance();
 
-                    arg_nodes.push(expr);
-                }
+        parse_result.success(self.arena.call_node(
+            node_to_call,
+            arg_nodes,
+            keyword_arg_nodes,
+            closing_call,
+        ))
+    }
 
-                if self.current_token_ref().token_type != TokenType::TT_RPAREN {
-                    return parse_result.failure(StandardError::new(
-                        "expected ',' or ')'",
-                        self.current_span(),
-                        Some("add a ',' to input all the function arguments or close with a ')' to call the function"),
-                    ));
+    fn index_expr(&mut self, node_to_index: NodeID) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        // every part of a slice 'start:end:step' is optional, so each one is only parsed if present
+        let mut start: Option<NodeID> = None;
+
+        if self.current_token_ref().token_type != TokenType::TT_COLON {
+            start = Some(parse_result.register(self.expr()));
+
+            if parse_result.error.is_some() {
+                return parse_result.failure(StandardError::new(
+                    "expected index",
+                    self.current_span(),
+                    Some("add an index like '0' or a slice like '1:3' between the brackets"),
+                ));
+            }
+
+            if self.current_token_ref().token_type == TokenType::TT_RSQUARE {
+                let closing_bracket = self.current_token_copy();
+
+                parse_result.register_advancement();
+                self.advance();
+
+                return parse_result.success(self.arena.index_node(
+                    node_to_index,
+                    start.unwrap(),
+                    closing_bracket,
+                ));
+            }
+        }
+
+        if self.current_token_ref().token_type != TokenType::TT_COLON {
+            return parse_result.failure(StandardError::new(
+                "expected ']' or ':'",
+                self.current_span(),
+                Some("add a ']' to close the index or a ':' to slice"),
+            ));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let mut end: Option<NodeID> = None;
+        let mut step: Option<NodeID> = None;
+
+        if !matches!(
+            self.current_token_ref().token_type,
+            TokenType::TT_COLON | TokenType::TT_RSQUARE
+        ) {
+            end = Some(parse_result.register(self.expr()));
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+        }
+
+        if self.current_token_ref().token_type == TokenType::TT_COLON {
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type != TokenType::TT_RSQUARE {
+                step = Some(parse_result.register(self.expr()));
+
+                if parse_result.error.is_some() {
+                    return parse_result;
                 }
+            }
+        }
+
+        if self.current_token_ref().token_type != TokenType::TT_RSQUARE {
+            return parse_result.failure(StandardError::new(
+                "expected ']'",
+                self.current_span(),
+                Some("add a ']' to close the slice"),
+            ));
+        }
 
-                closing_call = self.current_token_copy();
+        let closing_bracket = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.slice_node(
+            node_to_index,
+            start,
+            end,
+            step,
+            closing_bracket,
+        ))
+    }
+
+    fn interpolation_expr(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+        let opening_quote = self.current_token_copy();
+        let mut part_nodes: Vec<NodeID> = Vec::new();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        // the lexer splits the string into text parts and bracketed expressions like '{name}'
+        while self.current_token_ref().token_type != TokenType::TT_INTERPOLATION_END {
+            if self.current_token_ref().token_type == TokenType::TT_STR {
+                let text = self.current_token_copy();
 
                 parse_result.register_advancement();
                 self.advance();
+
+                part_nodes.push(self.arena.string_node(text));
+
+                continue;
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            if self.current_token_ref().token_type == TokenType::TT_RBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected expression",
+                    self.current_span(),
+                    Some("add an expression between the brackets like '{name}' or use '\\{' for a bracket"),
+                ));
             }
 
-            return parse_result.success(self.arena.call_node(atom, arg_nodes, closing_call));
+            let expr = parse_result.register(self.expr());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_RBRACKET {
+                return parse_result.failure(StandardError::new(
+                    "expected '}'",
+                    self.current_span(),
+                    Some("add a '}' to close the interpolated expression"),
+                ));
+            }
+
+            parse_result.register_advancement();
+            self.advance();
+
+            part_nodes.push(expr);
         }
 
-        parse_result.success(atom)
+        let closing_quote = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.interpolation_node(
+            part_nodes,
+            opening_quote,
+            closing_quote,
+        ))
+    }
+
+    fn member_expr(&mut self, node_to_access: NodeID) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+            return parse_result.failure(StandardError::new(
+                "expected identifier",
+                self.current_span(),
+                Some("add the name of a field or method after the '.' like 'name'"),
+            ));
+        }
+
+        let member_name = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.member_access_node(node_to_access, member_name))
     }
 
     fn atom(&mut self) -> ParseResult {
@@ -1180,6 +2105,26 @@ impl Parser {
             self.advance();
 
             return parse_result.success(self.arena.string_node(token));
+        } else if token.token_type == TokenType::TT_INTERPOLATION_START {
+            let expr = parse_result.register(self.interpolation_expr());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            return parse_result.success(expr);
+        } else if token.matches(TokenType::TT_KEYWORD, "true")
+            || token.matches(TokenType::TT_KEYWORD, "false")
+        {
+            parse_result.register_advancement();
+            self.advance();
+
+            return parse_result.success(self.arena.boolean_node(token));
+        } else if token.matches(TokenType::TT_KEYWORD, "null") {
+            parse_result.register_advancement();
+            self.advance();
+
+            return parse_result.success(self.arena.null_node(token));
         } else if token.token_type == TokenType::TT_IDENTIFIER {
             parse_result.register_advancement();
             self.advance();
@@ -1214,6 +2159,14 @@ impl Parser {
                 return parse_result;
             }
 
+            return parse_result.success(expr);
+        } else if token.token_type == TokenType::TT_LBRACKET {
+            let expr = parse_result.register(self.map_expr());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
             return parse_result.success(expr);
         } else if token.matches(TokenType::TT_KEYWORD, "if") {
             let expr = parse_result.register(self.if_expr());
@@ -1222,6 +2175,14 @@ impl Parser {
                 return parse_result;
             }
 
+            return parse_result.success(expr);
+        } else if token.matches(TokenType::TT_KEYWORD, "sniff") {
+            let expr = parse_result.register(self.match_expr());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
             return parse_result.success(expr);
         } else if token.matches(TokenType::TT_KEYWORD, "walk") {
             let expr = parse_result.register(self.for_expr());
@@ -1255,6 +2216,14 @@ impl Parser {
             }
 
             return parse_result.success(func_def);
+        } else if token.matches(TokenType::TT_KEYWORD, "breed") {
+            let breed_def = parse_result.register(self.breed_definition());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            return parse_result.success(breed_def);
         } else if token.matches(TokenType::TT_KEYWORD, "fetch") {
             let import_expr = parse_result.register(self.import_expr());
 
@@ -1305,6 +2274,7 @@ impl Parser {
             &[
                 (TokenType::TT_MUL, ""),
                 (TokenType::TT_DIV, ""),
+                (TokenType::TT_FLOORDIV, ""),
                 (TokenType::TT_MOD, ""),
             ],
             None,
@@ -1358,53 +2328,102 @@ impl Parser {
         self.advance();
 
         let mut arg_name_tokens: Vec<Token> = Vec::new();
+        let mut arg_type_tokens: Vec<Option<Token>> = Vec::new();
+        let mut default_nodes: Vec<Option<NodeID>> = Vec::new();
+        let mut rest_arg_name_token: Option<Token> = None;
 
-        if self.current_token_ref().token_type == TokenType::TT_IDENTIFIER {
-            arg_name_tokens.push(self.current_token_copy());
+        while self.current_token_ref().token_type != TokenType::TT_RPAREN {
+            if rest_arg_name_token.is_some() {
+                return parse_result.failure(StandardError::new(
+                    "expected ')'",
+                    self.current_span(),
+                    Some("the argument starting with '...' must be the last argument"),
+                ));
+            }
+
+            let is_rest_arg = self.current_token_ref().token_type == TokenType::TT_ELLIPSIS;
+
+            if is_rest_arg {
+                parse_result.register_advancement();
+                self.advance();
+            }
+
+            if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+                return parse_result.failure(StandardError::new(
+                    "expected identifier",
+                    self.current_span(),
+                    Some("add a name for the function arguments like 'name'"),
+                ));
+            }
+
+            let arg_name_token = self.current_token_copy();
 
             parse_result.register_advancement();
             self.advance();
 
-            while self.current_token_ref().token_type == TokenType::TT_COMMA {
+            let (type_result, type_token) = if is_rest_arg {
+                (ParseResult::new(), None)
+            } else {
+                self.type_annotation()
+            };
+            parse_result.register(type_result);
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            if is_rest_arg {
+                rest_arg_name_token = Some(arg_name_token);
+            } else if self.current_token_ref().token_type == TokenType::TT_EQ {
                 parse_result.register_advancement();
                 self.advance();
 
-                if self.current_token_ref().token_type == TokenType::TT_RPAREN {
-                    break;
-                }
+                let default = parse_result.register(self.expr());
 
-                if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
-                    return parse_result.failure(StandardError::new(
-                        "expected identifier",
-                        self.current_span(),
-                        Some("add a name for the function arguments like 'name'"),
-                    ));
+                if parse_result.error.is_some() {
+                    return parse_result;
                 }
 
-                arg_name_tokens.push(self.current_token_copy());
+                arg_name_tokens.push(arg_name_token);
+                arg_type_tokens.push(type_token);
+                default_nodes.push(Some(default));
+            } else if default_nodes
+                .last()
+                .is_some_and(|default| default.is_some())
+            {
+                return parse_result.failure(StandardError::new(
+                    "expected default value",
+                    arg_name_token.span,
+                    Some("arguments after an argument with a default value need one too, like 'greeting = \"hi\"'"),
+                ));
+            } else {
+                arg_name_tokens.push(arg_name_token);
+                arg_type_tokens.push(type_token);
+                default_nodes.push(None);
+            }
 
+            if self.current_token_ref().token_type == TokenType::TT_COMMA {
                 parse_result.register_advancement();
                 self.advance();
-            }
-
-            if self.current_token_ref().token_type != TokenType::TT_RPAREN {
+            } else if self.current_token_ref().token_type != TokenType::TT_RPAREN {
                 return parse_result.failure(StandardError::new(
-                    "expected ')'",
+                    "expected ',' or ')'",
                     self.current_span(),
                     Some("add another function argument or complete the function with ')'"),
                 ));
             }
-        } else if self.current_token_ref().token_type != TokenType::TT_RPAREN {
-            return parse_result.failure(StandardError::new(
-                "expected indentifier or ')'",
-                self.current_span(),
-                Some("add a name for the function arguments like 'name' or complete the function with ')'"),
-            ));
         }
 
         parse_result.register_advancement();
         self.advance();
 
+        let (type_result, return_type_token) = self.type_annotation();
+        parse_result.register(type_result);
+
+        if parse_result.error.is_some() {
+            return parse_result;
+        }
+
         if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
             return parse_result.failure(StandardError::new(
                 "expected '{'",
@@ -1416,7 +2435,9 @@ impl Parser {
         parse_result.register_advancement();
         self.advance();
 
+        self.function_tosses.push(false);
         let body = parse_result.register(self.statements());
+        let is_generator = self.function_tosses.pop().unwrap_or(false);
 
         if parse_result.error.is_some() {
             return parse_result;
@@ -1436,8 +2457,124 @@ impl Parser {
         parse_result.success(self.arena.function_definition_node(
             var_name_token,
             &arg_name_tokens,
+            arg_type_tokens,
+            default_nodes,
+            rest_arg_name_token,
+            return_type_token,
             body,
             false,
+            is_generator,
+        ))
+    }
+
+    /// Parses an optional type after a ':', like 'number' in 'obj count: number = 0;'
+    fn type_annotation(&mut self) -> (ParseResult, Option<Token>) {
+        let mut parse_result = ParseResult::new();
+
+        if self.current_token_ref().token_type != TokenType::TT_COLON {
+            return (parse_result, None);
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let type_token = self.current_token_copy();
+
+        // 'null' is a keyword, but it is also the name of its own type
+        if type_token.token_type != TokenType::TT_IDENTIFIER
+            && !type_token.matches(TokenType::TT_KEYWORD, "null")
+        {
+            return (
+                parse_result.failure(StandardError::new(
+                    "expected type",
+                    self.current_span(),
+                    Some("add a type after the ':' like 'number', 'string' or the name of a breed"),
+                )),
+                None,
+            );
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        (parse_result, Some(type_token))
+    }
+
+    fn breed_definition(&mut self) -> ParseResult {
+        let mut parse_result = ParseResult::new();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_IDENTIFIER {
+            return parse_result.failure(StandardError::new(
+                "expected identifier",
+                self.current_span(),
+                Some("add a name for this breed like 'Dog'"),
+            ));
+        }
+
+        let name_token = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        if self.current_token_ref().token_type != TokenType::TT_LBRACKET {
+            return parse_result.failure(StandardError::new(
+                "expected '{'",
+                self.current_span(),
+                Some("add a '{' to define the methods of the breed"),
+            ));
+        }
+
+        parse_result.register_advancement();
+        self.advance();
+
+        let mut method_nodes: Vec<NodeID> = Vec::new();
+
+        loop {
+            if self.current_token_ref().token_type == TokenType::TT_SEMICOLON {
+                parse_result.register_advancement();
+                self.advance();
+            }
+
+            if self.current_token_ref().token_type == TokenType::TT_RBRACKET {
+                break;
+            }
+
+            let next_tok = self.next_token_copy();
+
+            // only named functions are allowed inside of a breed
+            if !self
+                .current_token_ref()
+                .matches(TokenType::TT_KEYWORD, "func")
+                || next_tok.is_none_or(|tok| tok.token_type != TokenType::TT_IDENTIFIER)
+            {
+                return parse_result.failure(StandardError::new(
+                    "expected method or '}'",
+                    self.current_span(),
+                    Some("add a method like 'func bark(self) { ... }' or close the breed with '}'"),
+                ));
+            }
+
+            let method = parse_result.register(self.func_definition());
+
+            if parse_result.error.is_some() {
+                return parse_result;
+            }
+
+            method_nodes.push(method);
+        }
+
+        let closing_bracket = self.current_token_copy();
+
+        parse_result.register_advancement();
+        self.advance();
+
+        parse_result.success(self.arena.breed_definition_node(
+            name_token,
+            method_nodes,
+            closing_bracket,
         ))
     }
 
@@ -1492,7 +2629,6 @@ impl Parser {
 // Test the output AST from parsed tokens
 #[test]
 fn test_ast() {
-    use crate::AstNode;
     use glang_lexer::Lexer;
     use std::path::Path;
 
@@ -1,11 +1,28 @@
 echo "Building release binary (optimized)"
 cargo build --release
 echo "Running tests..."
+target/release/glang check tests/test_annotations.glang || exit 1
+target/release/glang tests/test_annotations.glang
+target/release/glang tests/test_arguments.glang
+target/release/glang tests/test_assignment.glang
+target/release/glang tests/test_booleans.glang
+target/release/glang tests/test_breeds.glang
 target/release/glang tests/test_comparisons.glang
 target/release/glang tests/test_constants.glang
+target/release/glang tests/test_destructuring.glang
 target/release/glang tests/test_imports.glang
+target/release/glang tests/test_indexing.glang
+target/release/glang tests/test_interpolation.glang
+target/release/glang tests/test_iterators.glang
+target/release/glang tests/test_logical.glang
 target/release/glang tests/test_loop.glang
+target/release/glang tests/test_maps.glang
+target/release/glang tests/test_methods.glang
 target/release/glang tests/test_mutability.glang
+target/release/glang tests/test_numbers.glang
 target/release/glang tests/test_recursion.glang
 target/release/glang tests/test_scope.glang
+target/release/glang tests/test_sets.glang
+target/release/glang tests/test_sniff.glang
+target/release/glang tests/test_string_literals.glang
 target/release/glang tests/test_try.glang